@@ -0,0 +1,287 @@
+//! Authority with optional userinfo.
+
+use crate::{HostPort, HostPortParseError};
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// Represents an authority in the form `[userinfo@]host:port`, e.g.
+/// `user:pass@proxy.quake.se:8080`.
+///
+/// `Debug` and `Display` redact the password as `***`, see
+/// [`Authority::to_unredacted_string`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Authority {
+    /// Userinfo (`user` or `user:password`), if any.
+    userinfo: Option<String>,
+
+    /// Host and port.
+    hostport: HostPort,
+}
+
+impl Authority {
+    /// Returns the userinfo part (`user` or `user:password`), if any.
+    #[must_use]
+    pub fn userinfo(&self) -> Option<&str> {
+        self.userinfo.as_deref()
+    }
+
+    /// Returns the username of the userinfo part, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Authority;
+    ///
+    /// let authority = Authority::try_from("user:pass@quake.se:8080").unwrap();
+    /// assert_eq!(authority.username(), Some("user"));
+    /// assert_eq!(authority.password(), Some("pass"));
+    /// ```
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        self.userinfo
+            .as_deref()
+            .map(|userinfo| userinfo.split_once(':').map_or(userinfo, |(user, _)| user))
+    }
+
+    /// Returns the password of the userinfo part, if any.
+    #[must_use]
+    pub fn password(&self) -> Option<&str> {
+        self.userinfo
+            .as_deref()
+            .and_then(|userinfo| userinfo.split_once(':'))
+            .map(|(_, password)| password)
+    }
+
+    /// Returns the host and port.
+    #[must_use]
+    pub fn hostport(&self) -> &HostPort {
+        &self.hostport
+    }
+
+    /// Converts into the `HostPort`, discarding any userinfo.
+    #[must_use]
+    pub fn into_hostport(self) -> HostPort {
+        self.hostport
+    }
+
+    /// Returns the authority as a string including the password, unlike
+    /// `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Authority;
+    ///
+    /// let authority = Authority::try_from("user:pass@quake.se:8080").unwrap();
+    /// assert_eq!(authority.to_string(), "user:***@quake.se:8080");
+    /// assert_eq!(authority.to_unredacted_string(), "user:pass@quake.se:8080");
+    /// ```
+    #[must_use]
+    pub fn to_unredacted_string(&self) -> String {
+        match &self.userinfo {
+            Some(userinfo) => format!("{}@{}", userinfo, self.hostport),
+            None => self.hostport.to_string(),
+        }
+    }
+
+    /// Returns the userinfo with the password replaced by `***`.
+    fn redacted_userinfo(&self) -> Option<String> {
+        let username = self.username()?;
+        match self.password() {
+            Some(_) => Some(format!("{username}:***")),
+            None => Some(username.to_string()),
+        }
+    }
+}
+
+/// Parses an authority in the form `[userinfo@]host:port`.
+///
+/// # Examples
+/// ```
+/// use hostport::Authority;
+///
+/// let authority = Authority::try_from("user:pass@quake.se:8080").unwrap();
+/// assert_eq!(authority.userinfo(), Some("user:pass"));
+/// assert_eq!(authority.hostport(), &"quake.se:8080");
+///
+/// let authority = Authority::try_from("quake.se:8080").unwrap();
+/// assert_eq!(authority.userinfo(), None);
+/// ```
+impl TryFrom<&str> for Authority {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (userinfo, hostport) = split_userinfo(value)?;
+        Ok(Authority {
+            userinfo: userinfo.map(str::to_string),
+            hostport: HostPort::try_from(hostport)?,
+        })
+    }
+}
+
+impl FromStr for Authority {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Authority::try_from(value)
+    }
+}
+
+impl From<HostPort> for Authority {
+    fn from(hostport: HostPort) -> Self {
+        Authority {
+            userinfo: None,
+            hostport,
+        }
+    }
+}
+
+impl Debug for Authority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Authority")
+            .field("userinfo", &self.redacted_userinfo())
+            .field("hostport", &self.hostport)
+            .finish()
+    }
+}
+
+impl Display for Authority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.redacted_userinfo() {
+            Some(userinfo) => write!(f, "{}@{}", userinfo, self.hostport),
+            None => write!(f, "{}", self.hostport),
+        }
+    }
+}
+
+/// Splits a leading `userinfo@` from the value, if present.
+pub(crate) fn split_userinfo(value: &str) -> Result<(Option<&str>, &str), HostPortParseError> {
+    match value.rsplit_once('@') {
+        Some((userinfo, _)) if !is_valid_userinfo(userinfo) => {
+            Err(HostPortParseError::InvalidUserinfo(userinfo.to_string()))
+        }
+        Some((userinfo, rest)) => Ok((Some(userinfo), rest)),
+        None => Ok((None, value)),
+    }
+}
+
+/// Checks the userinfo characters allowed by RFC 3986 (unreserved,
+/// percent-encoded, sub-delims and `:`).
+fn is_valid_userinfo(value: &str) -> bool {
+    !value.is_empty()
+        && value.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(
+                    c,
+                    '-' | '.'
+                        | '_'
+                        | '~'
+                        | '%'
+                        | '!'
+                        | '$'
+                        | '&'
+                        | '\''
+                        | '('
+                        | ')'
+                        | '*'
+                        | '+'
+                        | ','
+                        | ';'
+                        | '='
+                        | ':'
+                )
+        })
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        {
+            let authority = Authority::try_from("user:pass@quake.se:8080")?;
+            assert_eq!(authority.userinfo(), Some("user:pass"));
+            assert_eq!(authority.username(), Some("user"));
+            assert_eq!(authority.password(), Some("pass"));
+            assert_eq!(authority.hostport(), &HostPort::new("quake.se", 8080)?);
+        }
+        {
+            let authority = Authority::try_from("user@[::1]:8080")?;
+            assert_eq!(authority.userinfo(), Some("user"));
+            assert_eq!(authority.username(), Some("user"));
+            assert_eq!(authority.password(), None);
+            assert_eq!(authority.into_hostport(), HostPort::new("::1", 8080)?);
+        }
+        {
+            let authority = Authority::try_from("quake.se:8080")?;
+            assert_eq!(authority.userinfo(), None);
+            assert_eq!(authority.username(), None);
+            assert_eq!(authority.password(), None);
+        }
+
+        assert_eq!(
+            Authority::try_from("@quake.se:8080"),
+            Err(HostPortParseError::InvalidUserinfo("".to_string()))
+        );
+        assert_eq!(
+            Authority::try_from("us er@quake.se:8080"),
+            Err(HostPortParseError::InvalidUserinfo("us er".to_string()))
+        );
+        assert_eq!(
+            Authority::try_from("user@quake.se"),
+            Err(HostPortParseError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        for value in [
+            "user:pass@quake.se:8080",
+            "user@[::1]:8080",
+            "quake.se:8080",
+        ] {
+            assert_eq!(Authority::from_str(value)?.to_unredacted_string(), value);
+        }
+        for (value, expected) in [
+            ("user:pass@quake.se:8080", "user:***@quake.se:8080"),
+            ("user:@quake.se:8080", "user:***@quake.se:8080"),
+            ("user@[::1]:8080", "user@[::1]:8080"),
+            ("quake.se:8080", "quake.se:8080"),
+        ] {
+            assert_eq!(Authority::from_str(value)?.to_string(), expected);
+        }
+        assert_eq!(
+            Authority::from(HostPort::new("quake.se", 8080)?).to_string(),
+            "quake.se:8080"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug() -> Result<()> {
+        let authority = Authority::try_from("user:pass@quake.se:8080")?;
+        let debug = format!("{authority:?}");
+        assert!(!debug.contains("pass"), "{debug}");
+        assert!(debug.contains("user:***"), "{debug}");
+        assert!(!format!("{authority:#?}").contains("pass"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_valid_userinfo() {
+        // invalid
+        assert!(!is_valid_userinfo(""));
+        assert!(!is_valid_userinfo("us er"));
+        assert!(!is_valid_userinfo("user/name"));
+        assert!(!is_valid_userinfo("user@name"));
+
+        // valid
+        assert!(is_valid_userinfo("user"));
+        assert!(is_valid_userinfo("user:pass"));
+        assert!(is_valid_userinfo("user:"));
+        assert!(is_valid_userinfo("us%20er:p!a$s"));
+    }
+}
@@ -0,0 +1,153 @@
+//! Builder for `HostPort`.
+
+use crate::{Host, HostPort, HostPortParseError};
+
+/// Builds a [`HostPort`] from parts that may arrive from different sources,
+/// applying a default port late. Validation happens in [`build`](Self::build).
+///
+/// # Examples
+/// ```
+/// use hostport::HostPort;
+///
+/// let hostport = HostPort::builder()
+///     .host("quake.se")
+///     .default_port(27500)
+///     .build()
+///     .unwrap();
+/// assert_eq!(hostport, "quake.se:27500");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostPortBuilder {
+    host: Option<String>,
+    port: Option<u16>,
+    default_port: Option<u16>,
+}
+
+impl HostPortBuilder {
+    /// Creates an empty builder, same as [`HostPort::builder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the host.
+    #[must_use]
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_string());
+        self
+    }
+
+    /// Sets the port.
+    #[must_use]
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the port used if no port is set.
+    #[must_use]
+    pub fn default_port(mut self, port: u16) -> Self {
+        self.default_port = Some(port);
+        self
+    }
+
+    /// Validates the parts and builds the `HostPort`. A missing host results in
+    /// `InvalidHost`, and a missing port (without default) in `InvalidPort`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, HostPortParseError};
+    ///
+    /// let builder = HostPort::builder().host("quake.se").default_port(27500);
+    /// assert_eq!(builder.clone().port(28000).build().unwrap(), "quake.se:28000");
+    /// assert_eq!(builder.build().unwrap(), "quake.se:27500");
+    ///
+    /// assert_eq!(
+    ///     HostPort::builder().host("quake.se").build(),
+    ///     Err(HostPortParseError::InvalidPort("".to_string()))
+    /// );
+    /// ```
+    pub fn build(self) -> Result<HostPort, HostPortParseError> {
+        let host = Host::new(self.host.as_deref().unwrap_or_default())?;
+        let port = self
+            .port
+            .or(self.default_port)
+            .ok_or_else(|| HostPortParseError::InvalidPort(String::new()))?;
+        Ok(HostPort { host, port })
+    }
+}
+
+impl HostPort {
+    /// Returns a [`HostPortBuilder`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::builder().host("quake.se").port(28000).build().unwrap();
+    /// assert_eq!(hostport, "quake.se:28000");
+    /// ```
+    #[must_use]
+    pub fn builder() -> HostPortBuilder {
+        HostPortBuilder::new()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_build() -> Result<()> {
+        // valid
+        assert_eq!(
+            HostPort::builder().host("quake.se").port(28000).build()?,
+            "quake.se:28000"
+        );
+        assert_eq!(
+            HostPort::builder()
+                .default_port(27500)
+                .port(28000)
+                .host("::1")
+                .build()?,
+            "[::1]:28000"
+        );
+        assert_eq!(
+            HostPort::builder()
+                .host("localhost")
+                .default_port(27500)
+                .build()?,
+            "localhost:27500"
+        );
+        assert_eq!(
+            HostPort::builder()
+                .host("quake.se")
+                .host("qw.quake.se")
+                .port(0)
+                .build()?,
+            "qw.quake.se:0"
+        );
+
+        // invalid
+        assert_eq!(
+            HostPort::builder().port(28000).build(),
+            Err(HostPortParseError::InvalidHost("".to_string()))
+        );
+        assert_eq!(
+            HostPort::builder().host("quake..se").port(28000).build(),
+            Err(HostPortParseError::InvalidHost("quake..se".to_string()))
+        );
+        assert_eq!(
+            HostPort::builder().host("quake.se").build(),
+            Err(HostPortParseError::InvalidPort("".to_string()))
+        );
+        assert_eq!(
+            HostPort::builder().build(),
+            Err(HostPortParseError::InvalidHost("".to_string()))
+        );
+        Ok(())
+    }
+}
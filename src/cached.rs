@@ -0,0 +1,135 @@
+//! `HostPort` with a cached string rendering.
+
+use crate::HostPort;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A [`HostPort`] together with its `host:port` rendering, for hot paths such
+/// as logging that would otherwise call `to_string()` on every use.
+///
+/// Derefs to `HostPort`, and compares and hashes like it.
+///
+/// # Examples
+/// ```
+/// use hostport::{CachedHostPort, HostPort};
+///
+/// let server = CachedHostPort::new(HostPort::new("::1", 28000).unwrap());
+/// assert_eq!(server.as_str(), "[::1]:28000");
+/// assert_eq!(server.port(), 28000);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedHostPort {
+    hostport: HostPort,
+    rendered: String,
+}
+
+impl CachedHostPort {
+    /// Creates a new `CachedHostPort`, rendering the `HostPort` once.
+    #[must_use]
+    pub fn new(hostport: HostPort) -> Self {
+        let rendered = hostport.to_string();
+        Self { hostport, rendered }
+    }
+
+    /// Returns the `host:port` rendering, same as `to_string()` on the `HostPort`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.rendered
+    }
+
+    /// Returns the `HostPort`.
+    #[must_use]
+    pub fn as_hostport(&self) -> &HostPort {
+        &self.hostport
+    }
+
+    /// Consumes the `CachedHostPort`, returning the `HostPort`.
+    #[must_use]
+    pub fn into_inner(self) -> HostPort {
+        self.hostport
+    }
+}
+
+impl HostPort {
+    /// Returns a [`CachedHostPort`] holding this `HostPort` and its rendering.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let server = HostPort::new("quake.se", 28000).unwrap().into_cached();
+    /// assert_eq!(server.as_str(), "quake.se:28000");
+    /// ```
+    #[must_use]
+    pub fn into_cached(self) -> CachedHostPort {
+        CachedHostPort::new(self)
+    }
+}
+
+impl From<HostPort> for CachedHostPort {
+    fn from(hostport: HostPort) -> Self {
+        CachedHostPort::new(hostport)
+    }
+}
+
+impl Deref for CachedHostPort {
+    type Target = HostPort;
+
+    fn deref(&self) -> &HostPort {
+        &self.hostport
+    }
+}
+
+impl AsRef<str> for CachedHostPort {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CachedHostPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() || f.width().is_some() {
+            return self.hostport.fmt(f);
+        }
+        f.write_str(&self.rendered)
+    }
+}
+
+impl PartialEq for CachedHostPort {
+    fn eq(&self, other: &Self) -> bool {
+        self.hostport == other.hostport
+    }
+}
+
+impl Eq for CachedHostPort {}
+
+impl Hash for CachedHostPort {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hostport.hash(state);
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_cached_hostport() -> Result<()> {
+        let hostport = HostPort::new("2001:0DB8::0001", 28000)?;
+        let cached = hostport.clone().into_cached();
+        assert_eq!(cached.as_str(), "[2001:db8::1]:28000");
+        assert_eq!(cached.as_str(), hostport.to_string());
+        assert_eq!(cached.to_string(), hostport.to_string());
+        assert_eq!(format!("{cached:>22}"), format!("{hostport:>22}"));
+        assert_eq!(format!("{cached:#}"), "[2001:db8::1]");
+        assert_eq!(cached.host(), "2001:0DB8::0001");
+        assert_eq!(cached.as_hostport(), &hostport);
+        assert_eq!(cached, CachedHostPort::from(hostport.clone()));
+        assert_eq!(cached.into_inner(), hostport);
+        Ok(())
+    }
+}
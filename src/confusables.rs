@@ -0,0 +1,251 @@
+//! Homograph detection for internationalized hosts.
+
+use crate::HostPort;
+
+/// Non-Latin characters that render like Latin letters (subset of the Unicode
+/// confusables data, UTS #39), with their Latin prototype.
+const CONFUSABLES: [(char, char); 31] = [
+    // Cyrillic
+    ('а', 'a'),
+    ('с', 'c'),
+    ('ԁ', 'd'),
+    ('е', 'e'),
+    ('һ', 'h'),
+    ('і', 'i'),
+    ('ј', 'j'),
+    ('к', 'k'),
+    ('ӏ', 'l'),
+    ('м', 'm'),
+    ('п', 'n'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('ԛ', 'q'),
+    ('г', 'r'),
+    ('ѕ', 's'),
+    ('т', 't'),
+    ('ս', 'u'),
+    ('ѵ', 'v'),
+    ('ԝ', 'w'),
+    ('х', 'x'),
+    ('у', 'y'),
+    // Greek
+    ('α', 'a'),
+    ('ι', 'i'),
+    ('κ', 'k'),
+    ('ν', 'v'),
+    ('ο', 'o'),
+    ('ρ', 'p'),
+    ('τ', 't'),
+    ('υ', 'u'),
+    ('χ', 'x'),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Common,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Han,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Hangul,
+    Other,
+}
+
+/// Script combinations allowed within a label by the highly restrictive
+/// profile of UTS #39, for Japanese, Chinese and Korean.
+const ALLOWED_SCRIPT_SETS: [&[Script]; 3] = [
+    &[
+        Script::Latin,
+        Script::Han,
+        Script::Hiragana,
+        Script::Katakana,
+    ],
+    &[Script::Latin, Script::Han, Script::Bopomofo],
+    &[Script::Latin, Script::Han, Script::Hangul],
+];
+
+fn script(c: char) -> Script {
+    match c {
+        '0'..='9' | '-' | '_' => Script::Common,
+        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => Script::Latin,
+        '\u{0370}'..='\u{03FF}' => Script::Greek,
+        '\u{0400}'..='\u{052F}' => Script::Cyrillic,
+        '\u{0530}'..='\u{058F}' => Script::Armenian,
+        '\u{3005}'..='\u{3007}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2FA1F}' => Script::Han,
+        '\u{3040}'..='\u{309F}' => Script::Hiragana,
+        '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => {
+            Script::Katakana
+        }
+        '\u{3100}'..='\u{312F}' | '\u{31A0}'..='\u{31BF}' => Script::Bopomofo,
+        '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+            Script::Hangul
+        }
+        _ => Script::Other,
+    }
+}
+
+/// Returns `true` if the label mixes scripts, other than the CJK combinations
+/// in [`ALLOWED_SCRIPT_SETS`].
+fn is_mixed_script(label: &str) -> bool {
+    let mut scripts = Vec::new();
+    for script in label.chars().map(script).filter(|&s| s != Script::Common) {
+        if !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+    scripts.len() > 1
+        && !ALLOWED_SCRIPT_SETS
+            .iter()
+            .any(|allowed| scripts.iter().all(|script| allowed.contains(script)))
+}
+
+/// Returns the skeleton of a Unicode string: lowercased, with confusable
+/// characters replaced by their Latin prototype.
+fn to_skeleton(value: &str) -> String {
+    value
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| {
+            CONFUSABLES
+                .iter()
+                .find(|&&(confusable, _)| confusable == c)
+                .map_or(c, |&(_, prototype)| prototype)
+        })
+        .collect()
+}
+
+/// Returns `true` if a single-script label is a whole-script confusable of a
+/// Latin label (UTS #39), e.g. Cyrillic `аррӏе` for `apple`.
+fn is_whole_script_confusable(label: &str) -> bool {
+    let skeleton = to_skeleton(label);
+    skeleton != label.to_lowercase()
+        && skeleton
+            .chars()
+            .all(|c| matches!(script(c), Script::Latin | Script::Common))
+}
+
+impl HostPort {
+    /// Returns the confusable skeleton of the host (UTS #39): the Unicode form,
+    /// lowercased, with characters that look like Latin letters replaced by those
+    /// letters. Hosts with equal skeletons are visually confusable.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let spoofed = HostPort::try_from("раураl.com:443").unwrap();
+    /// let genuine = HostPort::try_from("paypal.com:443").unwrap();
+    /// assert_ne!(spoofed, genuine);
+    /// assert_eq!(spoofed.skeleton(), genuine.skeleton());
+    /// ```
+    #[must_use]
+    pub fn skeleton(&self) -> String {
+        to_skeleton(&idna::domain_to_unicode(self.host()).0)
+    }
+
+    /// Returns `true` if the host may be spoofed, following the mixed-script and
+    /// whole-script rules of UTS #39: a label mixes scripts (e.g. Latin and
+    /// Cyrillic), or is written entirely in characters that look like Latin
+    /// letters. Ordinary Cyrillic or Greek hosts, and Latin mixed with Han, Kana,
+    /// Bopomofo or Hangul as in Japanese, Chinese and Korean, are not flagged.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// assert!(HostPort::try_from("раураl.com:443").unwrap().has_confusables());
+    /// assert!(!HostPort::try_from("paypal.com:443").unwrap().has_confusables());
+    /// assert!(!HostPort::try_from("bücher.example:443").unwrap().has_confusables());
+    /// assert!(!HostPort::try_from("пример.рф:443").unwrap().has_confusables());
+    /// ```
+    #[must_use]
+    pub fn has_confusables(&self) -> bool {
+        idna::domain_to_unicode(self.host())
+            .0
+            .split('.')
+            .any(|label| is_mixed_script(label) || is_whole_script_confusable(label))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_skeleton() -> Result<()> {
+        for (value, expected) in [
+            ("paypal.com:443", "paypal.com"),
+            ("PayPal.com:443", "paypal.com"),
+            ("раураl.com:443", "paypal.com"),
+            ("аррӏе.com:443", "apple.com"),
+            ("gοοgle.com:443", "google.com"),
+            ("bücher.example:443", "bücher.example"),
+            ("10.10.10.10:443", "10.10.10.10"),
+        ] {
+            assert_eq!(HostPort::try_from(value)?.skeleton(), expected, "{value}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_confusables() -> Result<()> {
+        // confusable
+        assert!(HostPort::try_from("раураl.com:443")?.has_confusables());
+        assert!(HostPort::try_from("аррӏе.com:443")?.has_confusables());
+        assert!(HostPort::try_from("gοοgle.com:443")?.has_confusables());
+        assert!(HostPort::try_from("xn--80ak6aa92e.com:443")?.has_confusables());
+
+        // not confusable
+        assert!(!HostPort::try_from("quake.se:28000")?.has_confusables());
+        assert!(!HostPort::try_from("bücher.example:443")?.has_confusables());
+        assert!(!HostPort::try_from("日本語.jp:443")?.has_confusables());
+        assert!(!HostPort::try_from("abcカナ.jp:443")?.has_confusables());
+        assert!(!HostPort::try_from("abc日本.jp:443")?.has_confusables());
+        assert!(!HostPort::try_from("пример.рф:443")?.has_confusables());
+        assert!(!HostPort::try_from("москва.рф:443")?.has_confusables());
+        assert!(!HostPort::try_from("παράδειγμα.gr:443")?.has_confusables());
+        assert!(!HostPort::try_from("ελλάδα.gr:443")?.has_confusables());
+        assert!(!HostPort::try_from("10.10.10.10:443")?.has_confusables());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_whole_script_confusable() {
+        assert!(is_whole_script_confusable("аррӏе"));
+        assert!(is_whole_script_confusable("οκ"));
+        assert!(!is_whole_script_confusable("пример"));
+        assert!(!is_whole_script_confusable("рф"));
+        assert!(!is_whole_script_confusable("παράδειγμα"));
+        assert!(!is_whole_script_confusable("apple"));
+        assert!(!is_whole_script_confusable("123"));
+    }
+
+    #[test]
+    fn test_is_mixed_script() {
+        assert!(is_mixed_script("pаypal"));
+        assert!(is_mixed_script("αb"));
+        assert!(is_mixed_script("аbcカナ"));
+        assert!(is_mixed_script("カナ한글"));
+        assert!(is_mixed_script("ㄅ日本かな"));
+        assert!(!is_mixed_script("paypal-1"));
+        assert!(!is_mixed_script("abcカナ"));
+        assert!(!is_mixed_script("abc日本"));
+        assert!(!is_mixed_script("日本語かなカナ"));
+        assert!(!is_mixed_script("abc中文ㄅㄆ"));
+        assert!(!is_mixed_script("abc한국漢字"));
+        assert!(!is_mixed_script("пример"));
+        assert!(!is_mixed_script("123"));
+        assert!(!is_mixed_script(""));
+    }
+}
@@ -0,0 +1,486 @@
+//! Parsing with diagnostics.
+
+use crate::validate::{self, HostRules, HostValidationError};
+use crate::{HostPort, host_to_ascii};
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// Result of [`HostPort::parse_diagnostic`]: the parsed value, if the input
+/// was recoverable, and all issues found in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// Parsed value, if no error was found.
+    hostport: Option<HostPort>,
+
+    /// Issues found in the input.
+    issues: Vec<Issue>,
+}
+
+impl Diagnostics {
+    /// Returns the parsed value, if only warnings were found, e.g. surrounding
+    /// whitespace.
+    #[must_use]
+    pub fn hostport(&self) -> Option<&HostPort> {
+        self.hostport.as_ref()
+    }
+
+    /// Returns the issues found in the input, ordered by position.
+    #[must_use]
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Returns `true` if no issues were found.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns `true` if any issue has [`Severity::Error`].
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.kind.severity() == Severity::Error)
+    }
+}
+
+/// Severity of an [`Issue`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The input is recoverable, e.g. surrounding whitespace.
+    Warning,
+
+    /// The input is invalid.
+    Error,
+}
+
+/// Issue found in the input, at a byte offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    /// Byte offset in the input.
+    pub position: usize,
+
+    /// Kind of issue.
+    pub kind: IssueKind,
+}
+
+/// Kind of issue found by [`HostPort::parse_diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IssueKind {
+    /// Leading or trailing whitespace.
+    SurroundingWhitespace,
+
+    /// Missing `:` separating host and port.
+    MissingSeparator,
+
+    /// Missing `]` closing an IPv6 host.
+    UnclosedBracket,
+
+    /// IPv6 host without brackets.
+    UnbracketedIpv6,
+
+    /// Empty host.
+    EmptyHost,
+
+    /// Host longer than 253 characters.
+    HostTooLong,
+
+    /// Invalid character in the host.
+    InvalidHostChar(char),
+
+    /// Empty label, e.g. `quake..se`.
+    EmptyLabel,
+
+    /// Label longer than 63 characters.
+    LabelTooLong,
+
+    /// Label starting or ending with `-`.
+    LabelHyphen,
+
+    /// All-numeric label.
+    NumericLabel,
+
+    /// Label starting with a digit.
+    LeadingDigit,
+
+    /// All-numeric top-level domain.
+    NumericTld,
+
+    /// Host with more labels than allowed.
+    TooManyLabels,
+
+    /// Malformed IPv4 address.
+    InvalidIpv4,
+
+    /// Malformed IPv6 address.
+    InvalidIpv6,
+
+    /// Empty port.
+    EmptyPort,
+
+    /// Invalid character in the port.
+    InvalidPortChar(char),
+
+    /// Port outside `0-65535`.
+    PortOutOfRange,
+}
+
+impl IssueKind {
+    /// Returns the severity of the issue.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{IssueKind, Severity};
+    ///
+    /// assert_eq!(IssueKind::SurroundingWhitespace.severity(), Severity::Warning);
+    /// assert_eq!(IssueKind::EmptyLabel.severity(), Severity::Error);
+    /// ```
+    #[must_use]
+    pub fn severity(&self) -> Severity {
+        match self {
+            IssueKind::SurroundingWhitespace => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueKind::SurroundingWhitespace => write!(f, "surrounding whitespace"),
+            IssueKind::MissingSeparator => write!(f, "missing ':' between host and port"),
+            IssueKind::UnclosedBracket => write!(f, "missing ']' after IPv6 host"),
+            IssueKind::UnbracketedIpv6 => write!(f, "IPv6 host must be enclosed in brackets"),
+            IssueKind::EmptyHost => write!(f, "empty host"),
+            IssueKind::HostTooLong => write!(f, "host longer than 253 characters"),
+            IssueKind::InvalidHostChar(c) => write!(f, "invalid character '{c}' in host"),
+            IssueKind::EmptyLabel => write!(f, "empty label"),
+            IssueKind::LabelTooLong => write!(f, "label longer than 63 characters"),
+            IssueKind::LabelHyphen => write!(f, "label starts or ends with '-'"),
+            IssueKind::NumericLabel => write!(f, "all-numeric label"),
+            IssueKind::LeadingDigit => write!(f, "label starts with a digit"),
+            IssueKind::NumericTld => write!(f, "all-numeric top-level domain"),
+            IssueKind::TooManyLabels => write!(f, "too many labels"),
+            IssueKind::InvalidIpv4 => write!(f, "invalid IPv4 address"),
+            IssueKind::InvalidIpv6 => write!(f, "invalid IPv6 address"),
+            IssueKind::EmptyPort => write!(f, "empty port"),
+            IssueKind::InvalidPortChar(c) => write!(f, "invalid character '{c}' in port"),
+            IssueKind::PortOutOfRange => write!(f, "port out of range 0-65535"),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl HostPort {
+    /// Parses a `HostPort` and reports all issues found in the input at once.
+    /// Hosts are checked with the default [`HostRules`], reporting the first
+    /// problem of each label. The value is returned only if no issue has
+    /// [`Severity::Error`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, IssueKind};
+    ///
+    /// let diagnostics = HostPort::parse_diagnostic(" quake.se:28000");
+    /// assert_eq!(diagnostics.hostport().unwrap(), &"quake.se:28000");
+    /// assert_eq!(diagnostics.issues()[0].kind, IssueKind::SurroundingWhitespace);
+    ///
+    /// let diagnostics = HostPort::parse_diagnostic("qu%ke.se:99999");
+    /// assert_eq!(diagnostics.hostport(), None);
+    /// assert_eq!(diagnostics.issues()[0].to_string(), "invalid character '%' in host at position 2");
+    /// assert_eq!(diagnostics.issues()[1].to_string(), "port out of range 0-65535 at position 9");
+    /// ```
+    #[must_use]
+    pub fn parse_diagnostic(value: &str) -> Diagnostics {
+        let mut issues = Vec::new();
+        let mut issue = |position: usize, kind: IssueKind| issues.push(Issue { position, kind });
+
+        let trimmed = value.trim();
+        let offset = value.len() - value.trim_start().len();
+        if trimmed.len() != value.len() {
+            let position = if offset > 0 { 0 } else { trimmed.len() };
+            issue(position, IssueKind::SurroundingWhitespace);
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('[') {
+            match rest.split_once(']') {
+                Some((host, rest)) => {
+                    if !validate::is_valid_scoped_ipv6(host) {
+                        issue(offset + 1, IssueKind::InvalidIpv6);
+                    }
+                    let port_offset = offset + 1 + host.len() + 1;
+                    match rest.strip_prefix(':') {
+                        Some(port) => check_port(port, port_offset + 1, &mut issue),
+                        None => issue(port_offset, IssueKind::MissingSeparator),
+                    }
+                }
+                None => issue(offset, IssueKind::UnclosedBracket),
+            }
+        } else {
+            match trimmed.rsplit_once(':') {
+                Some((host, port)) => {
+                    check_host(host, offset, &mut issue);
+                    check_port(port, offset + host.len() + 1, &mut issue);
+                }
+                None => {
+                    check_host(trimmed, offset, &mut issue);
+                    issue(offset + trimmed.len(), IssueKind::MissingSeparator);
+                }
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.position);
+        let hostport = issues
+            .iter()
+            .all(|issue| issue.kind.severity() == Severity::Warning)
+            .then(|| HostPort::try_from(trimmed).ok())
+            .flatten();
+        Diagnostics { hostport, issues }
+    }
+}
+
+fn check_host(host: &str, offset: usize, issue: &mut impl FnMut(usize, IssueKind)) {
+    if host.is_empty() {
+        return issue(offset, IssueKind::EmptyHost);
+    }
+    if host.contains(':') {
+        return issue(offset, IssueKind::UnbracketedIpv6);
+    }
+
+    let rules = HostRules::default();
+    let ascii_host = host_to_ascii(host);
+    if let Cow::Owned(ascii_host) = &ascii_host {
+        // positions in the converted host don't map back to the input
+        if let Err(error) = rules.validate_host(ascii_host) {
+            issue(offset, issue_kind(error).1);
+        }
+        return;
+    }
+
+    match rules.validate_host(host) {
+        Ok(()) => return,
+        Err(
+            error @ (HostValidationError::TooLong { .. }
+            | HostValidationError::TooManyLabels { .. }),
+        ) => issue(offset, issue_kind(error).1),
+        Err(
+            error @ (HostValidationError::InvalidIpv4
+            | HostValidationError::InvalidIpv6
+            | HostValidationError::NumericTld { .. }),
+        ) => {
+            let (position, kind) = issue_kind(error);
+            return issue(offset + position, kind);
+        }
+        Err(_) => {}
+    }
+
+    // report the first problem of every label
+    let mut label_offset = offset;
+    for label in host.split('.') {
+        if let Err(error) = rules.validate_host(label) {
+            let (position, kind) = match issue_kind(error) {
+                (position, IssueKind::EmptyHost) => (position, IssueKind::EmptyLabel),
+                (position, IssueKind::HostTooLong) => (position, IssueKind::LabelTooLong),
+                other => other,
+            };
+            issue(label_offset + position, kind);
+        }
+        label_offset += label.len() + 1;
+    }
+}
+
+/// Maps a host validation error to an issue, with the position relative to the host.
+fn issue_kind(error: HostValidationError) -> (usize, IssueKind) {
+    match error {
+        HostValidationError::Empty => (0, IssueKind::EmptyHost),
+        HostValidationError::TooLong { .. } => (0, IssueKind::HostTooLong),
+        HostValidationError::InvalidChar {
+            character,
+            position,
+        } => (position, IssueKind::InvalidHostChar(character)),
+        HostValidationError::EmptyLabel { position } => (position, IssueKind::EmptyLabel),
+        HostValidationError::LabelTooLong { position } => (position, IssueKind::LabelTooLong),
+        HostValidationError::BadLabelEdge { position } => (position, IssueKind::LabelHyphen),
+        HostValidationError::NumericLabel { position } => (position, IssueKind::NumericLabel),
+        HostValidationError::LeadingDigit { position } => (position, IssueKind::LeadingDigit),
+        HostValidationError::NumericTld { position } => (position, IssueKind::NumericTld),
+        HostValidationError::TooManyLabels { .. } => (0, IssueKind::TooManyLabels),
+        HostValidationError::InvalidIpv4 => (0, IssueKind::InvalidIpv4),
+        HostValidationError::InvalidIpv6 => (0, IssueKind::InvalidIpv6),
+    }
+}
+
+fn check_port(port: &str, offset: usize, issue: &mut impl FnMut(usize, IssueKind)) {
+    if port.is_empty() {
+        return issue(offset, IssueKind::EmptyPort);
+    }
+
+    let mut has_invalid_char = false;
+    for (index, c) in port.char_indices() {
+        if !c.is_ascii_digit() {
+            issue(offset + index, IssueKind::InvalidPortChar(c));
+            has_invalid_char = true;
+        }
+    }
+    if !has_invalid_char && port.parse::<u16>().is_err() {
+        issue(offset, IssueKind::PortOutOfRange);
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    fn issues(value: &str) -> Vec<(usize, IssueKind)> {
+        HostPort::parse_diagnostic(value)
+            .issues()
+            .iter()
+            .map(|issue| (issue.position, issue.kind.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_diagnostic_valid() -> Result<()> {
+        for value in [
+            "quake.se:28000",
+            "10.10.10.10:28000",
+            "[::1]:28000",
+            "localhost:0",
+        ] {
+            let diagnostics = HostPort::parse_diagnostic(value);
+            assert!(diagnostics.is_ok());
+            assert_eq!(diagnostics.hostport(), Some(&HostPort::try_from(value)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_diagnostic_recoverable() -> Result<()> {
+        let diagnostics = HostPort::parse_diagnostic("  quake.se:28000 ");
+        assert!(!diagnostics.is_ok());
+        assert_eq!(
+            diagnostics.hostport(),
+            Some(&HostPort::new("quake.se", 28000)?)
+        );
+        assert_eq!(
+            diagnostics.issues(),
+            &[Issue {
+                position: 0,
+                kind: IssueKind::SurroundingWhitespace
+            }]
+        );
+        assert_eq!(
+            issues("quake.se:28000 "),
+            vec![(14, IssueKind::SurroundingWhitespace)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_diagnostic_issues() {
+        assert_eq!(
+            issues("qu%ke.s|e:2a8b"),
+            vec![
+                (2, IssueKind::InvalidHostChar('%')),
+                (7, IssueKind::InvalidHostChar('|')),
+                (11, IssueKind::InvalidPortChar('a')),
+                (13, IssueKind::InvalidPortChar('b')),
+            ]
+        );
+        assert_eq!(
+            issues("qu%ke|se:1"),
+            vec![(2, IssueKind::InvalidHostChar('%'))]
+        );
+        assert_eq!(issues("quake-.se:1"), vec![(5, IssueKind::LabelHyphen)]);
+        assert_eq!(
+            issues("-quake..123:99999"),
+            vec![
+                (0, IssueKind::LabelHyphen),
+                (7, IssueKind::EmptyLabel),
+                (8, IssueKind::NumericLabel),
+                (12, IssueKind::PortOutOfRange),
+            ]
+        );
+        assert_eq!(issues("quake.se"), vec![(8, IssueKind::MissingSeparator)]);
+        assert_eq!(
+            issues(":"),
+            vec![(0, IssueKind::EmptyHost), (1, IssueKind::EmptyPort)]
+        );
+        assert_eq!(issues("1000.0.0.0:1"), vec![(0, IssueKind::InvalidIpv4)]);
+        assert_eq!(issues("::1:28000"), vec![(0, IssueKind::UnbracketedIpv6)]);
+        assert_eq!(issues("[::1:28000"), vec![(0, IssueKind::UnclosedBracket)]);
+        assert_eq!(issues("[::g]:28000"), vec![(1, IssueKind::InvalidIpv6)]);
+        assert_eq!(issues("[::1]28000"), vec![(5, IssueKind::MissingSeparator)]);
+        assert_eq!(issues("[::1]:"), vec![(6, IssueKind::EmptyPort)]);
+        assert_eq!(
+            issues(&format!("{}.se:1", "a".repeat(64))),
+            vec![(0, IssueKind::LabelTooLong)]
+        );
+        assert_eq!(
+            issues(&format!("{}:1", "a.".repeat(128))),
+            vec![(0, IssueKind::HostTooLong), (256, IssueKind::EmptyLabel)]
+        );
+    }
+
+    #[test]
+    fn test_parse_diagnostic_unrecoverable() {
+        let diagnostics = HostPort::parse_diagnostic("'quake.se:28000'");
+        assert_eq!(diagnostics.hostport(), None);
+        assert!(diagnostics.has_errors());
+        assert_eq!(
+            issues("'quake.se:28000'"),
+            vec![
+                (0, IssueKind::InvalidHostChar('\'')),
+                (15, IssueKind::InvalidPortChar('\'')),
+            ]
+        );
+
+        let diagnostics = HostPort::parse_diagnostic("quake.se.:28000");
+        assert_eq!(diagnostics.hostport(), None);
+        assert_eq!(issues("quake.se.:28000"), vec![(9, IssueKind::EmptyLabel)]);
+
+        let diagnostics = HostPort::parse_diagnostic(" quake..se:28000");
+        assert_eq!(diagnostics.hostport(), None);
+        assert!(diagnostics.has_errors());
+    }
+
+    #[test]
+    fn test_parse_diagnostic_idna() -> Result<()> {
+        let diagnostics = HostPort::parse_diagnostic("b\u{fc}cher.se:80");
+        if cfg!(feature = "idna") {
+            assert!(diagnostics.is_ok());
+            assert_eq!(
+                diagnostics.hostport(),
+                Some(&HostPort::try_from("xn--bcher-kva.se:80")?)
+            );
+        } else {
+            assert_eq!(diagnostics.hostport(), None);
+            assert_eq!(
+                issues("b\u{fc}cher.se:80"),
+                vec![(1, IssueKind::InvalidHostChar('\u{fc}'))]
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_issue_display() {
+        let issue = Issue {
+            position: 5,
+            kind: IssueKind::InvalidHostChar('%'),
+        };
+        assert_eq!(
+            issue.to_string(),
+            "invalid character '%' in host at position 5"
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Domain name or network alias, never an IP address.
+
+use crate::{Host, HostPort, HostPortParseError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Represents a validated host that is not an IP address, e.g. `quake.se` or `localhost`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Domain(Host);
+
+impl Domain {
+    /// Creates a new `Domain` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// let domain = Domain::new("quake.se").unwrap();
+    /// assert_eq!(domain.as_str(), "quake.se");
+    /// assert!(Domain::new("10.10.10.10").is_err());
+    /// ```
+    pub fn new(domain: &str) -> Result<Domain, HostPortParseError> {
+        Domain::try_from(Host::new(domain)?)
+    }
+
+    /// Returns the domain as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the top-level domain, or `None` for single-label network aliases.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// assert_eq!(Domain::new("qw.quake.se").unwrap().tld(), Some("se"));
+    /// assert_eq!(Domain::new("localhost").unwrap().tld(), None);
+    /// ```
+    #[must_use]
+    pub fn tld(&self) -> Option<&str> {
+        self.as_str().rsplit_once('.').map(|(_, tld)| tld)
+    }
+
+    /// Returns the labels of the domain, from left to right.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// let domain = Domain::new("qw.quake.se").unwrap();
+    /// assert_eq!(domain.labels().collect::<Vec<_>>(), vec!["qw", "quake", "se"]);
+    /// ```
+    pub fn labels(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.as_str().split('.')
+    }
+
+    /// Returns the parent domain, i.e. the domain without its leftmost label,
+    /// or `None` for single-label network aliases.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// let domain = Domain::new("qw.quake.se").unwrap();
+    /// assert_eq!(domain.parent().unwrap().as_str(), "quake.se");
+    /// assert_eq!(Domain::new("se").unwrap().parent(), None);
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<Domain> {
+        let (_, parent) = self.as_str().split_once('.')?;
+        Some(Domain(Host(parent.to_string().into())))
+    }
+
+    /// Returns the effective top-level domain (public suffix) per the public suffix
+    /// list, or `None` for single-label network aliases.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// assert_eq!(Domain::new("a.b.example.co.uk").unwrap().effective_tld(), Some("co.uk"));
+    /// assert_eq!(Domain::new("localhost").unwrap().effective_tld(), None);
+    /// ```
+    #[cfg(feature = "psl")]
+    #[must_use]
+    pub fn effective_tld(&self) -> Option<&str> {
+        self.public_suffix_part(psl::suffix_str)
+    }
+
+    /// Returns the registrable domain, i.e. the effective top-level domain plus one
+    /// label, per the public suffix list. Returns `None` if the domain is itself a
+    /// public suffix or a single-label network alias.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Domain;
+    ///
+    /// let domain = Domain::new("a.b.example.co.uk").unwrap();
+    /// assert_eq!(domain.registrable_domain(), Some("example.co.uk"));
+    /// assert_eq!(Domain::new("co.uk").unwrap().registrable_domain(), None);
+    /// ```
+    #[cfg(feature = "psl")]
+    #[must_use]
+    pub fn registrable_domain(&self) -> Option<&str> {
+        self.public_suffix_part(psl::domain_str)
+    }
+
+    /// Applies a public suffix lookup case-insensitively, returning the matching
+    /// suffix of the domain in its original case.
+    #[cfg(feature = "psl")]
+    fn public_suffix_part(&self, lookup: fn(&str) -> Option<&str>) -> Option<&str> {
+        let domain = self.as_str();
+        if !domain.contains('.') {
+            return None;
+        }
+        let len = lookup(&domain.to_ascii_lowercase())?.len();
+        Some(&domain[domain.len() - len..])
+    }
+
+    /// Returns the domain as a [`Host`].
+    #[must_use]
+    pub fn as_host(&self) -> &Host {
+        &self.0
+    }
+}
+
+impl HostPort {
+    /// Returns the host as a [`Domain`], or `None` if the host is an IP address.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("qw.quake.se", 28000).unwrap();
+    /// assert_eq!(hostport.domain().unwrap().parent().unwrap().as_str(), "quake.se");
+    /// assert_eq!(HostPort::new("10.10.10.10", 28000).unwrap().domain(), None);
+    /// ```
+    #[must_use]
+    pub fn domain(&self) -> Option<Domain> {
+        Domain::try_from(self.as_host().clone()).ok()
+    }
+
+    /// Returns a `HostPort` with the parent domain as host, i.e. without the
+    /// leftmost label, or `None` for IP addresses and single-label network aliases.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("eu.quake.se", 28000).unwrap();
+    /// assert_eq!(hostport.parent().unwrap(), "quake.se:28000");
+    /// assert_eq!(HostPort::new("localhost", 28000).unwrap().parent(), None);
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<HostPort> {
+        let parent = self.domain()?.parent()?;
+        Some(HostPort {
+            host: parent.0,
+            port: self.port(),
+        })
+    }
+
+    /// Returns a `HostPort` with `label` prepended to the host, e.g. `eu` for
+    /// `eu.quake.se`. The label must be a single valid label, and the host must
+    /// not be an IP address.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("quake.se", 28000).unwrap();
+    /// assert_eq!(hostport.with_subdomain("eu").unwrap(), "eu.quake.se:28000");
+    /// assert!(hostport.with_subdomain("-eu").is_err());
+    /// ```
+    pub fn with_subdomain(&self, label: &str) -> Result<HostPort, HostPortParseError> {
+        let host = format!("{label}.{}", self.host());
+        if label.contains('.') || self.ip().is_some() {
+            return Err(HostPortParseError::InvalidHost(host));
+        }
+        HostPort::new(&host, self.port())
+    }
+}
+
+#[cfg(feature = "psl")]
+impl HostPort {
+    /// Returns the effective top-level domain (public suffix) of the host, or `None`
+    /// for IP addresses and single-label network aliases. See [`Domain::effective_tld`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("a.b.example.co.uk", 28000).unwrap();
+    /// assert_eq!(hostport.effective_tld(), Some("co.uk"));
+    /// ```
+    #[must_use]
+    pub fn effective_tld(&self) -> Option<&str> {
+        let len = self.domain()?.effective_tld()?.len();
+        Some(&self.host()[self.host().len() - len..])
+    }
+
+    /// Returns the registrable domain of the host, or `None` for IP addresses, public
+    /// suffixes and single-label network aliases. See [`Domain::registrable_domain`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("a.b.example.co.uk", 28000).unwrap();
+    /// assert_eq!(hostport.registrable_domain(), Some("example.co.uk"));
+    /// ```
+    #[must_use]
+    pub fn registrable_domain(&self) -> Option<&str> {
+        let len = self.domain()?.registrable_domain()?.len();
+        Some(&self.host()[self.host().len() - len..])
+    }
+}
+
+impl TryFrom<Host> for Domain {
+    type Error = HostPortParseError;
+
+    fn try_from(host: Host) -> Result<Self, Self::Error> {
+        if host.ip().is_some() {
+            return Err(HostPortParseError::InvalidHost(host.0.into_owned()));
+        }
+        Ok(Domain(host))
+    }
+}
+
+impl TryFrom<&str> for Domain {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Domain::new(value)
+    }
+}
+
+impl FromStr for Domain {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Domain::new(value)
+    }
+}
+
+impl From<Domain> for Host {
+    fn from(domain: Domain) -> Self {
+        domain.0
+    }
+}
+
+impl Display for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for Domain {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        assert_eq!(Domain::new("quake.se")?.as_str(), "quake.se");
+        assert_eq!(Domain::new("localhost")?.as_str(), "localhost");
+        assert_eq!(
+            Domain::new("10.10.10.10"),
+            Err(HostPortParseError::InvalidHost("10.10.10.10".to_string()))
+        );
+        assert_eq!(
+            Domain::new("::1"),
+            Err(HostPortParseError::InvalidHost("::1".to_string()))
+        );
+        assert!(Domain::new("quake|se").is_err());
+        assert_eq!("quake.se".parse::<Domain>()?.to_string(), "quake.se");
+        Ok(())
+    }
+
+    #[test]
+    fn test_tld_labels_parent() -> Result<()> {
+        let domain = Domain::new("qw.quake.se")?;
+        assert_eq!(domain.tld(), Some("se"));
+        assert_eq!(
+            domain.labels().collect::<Vec<_>>(),
+            vec!["qw", "quake", "se"]
+        );
+        assert_eq!(domain.labels().next_back(), Some("se"));
+
+        let parent = domain.parent().unwrap();
+        assert_eq!(parent, Domain::new("quake.se")?);
+        assert_eq!(parent.parent(), Some(Domain::new("se")?));
+        assert_eq!(parent.parent().unwrap().parent(), None);
+
+        let alias = Domain::new("localhost")?;
+        assert_eq!(alias.tld(), None);
+        assert_eq!(alias.labels().collect::<Vec<_>>(), vec!["localhost"]);
+        assert_eq!(alias.parent(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hostport_parent_with_subdomain() -> Result<()> {
+        let hostport = HostPort::new("eu.quake.se", 28000)?;
+        let parent = hostport.parent().unwrap();
+        assert_eq!(parent, "quake.se:28000");
+        assert_eq!(parent.parent().unwrap(), "se:28000");
+        assert_eq!(parent.parent().unwrap().parent(), None);
+        assert_eq!(HostPort::new("10.10.10.10", 28000)?.parent(), None);
+        assert_eq!(HostPort::new("::1", 28000)?.parent(), None);
+
+        assert_eq!(parent.with_subdomain("eu")?, hostport);
+        assert_eq!(
+            HostPort::new("localhost", 28000)?.with_subdomain("api")?,
+            "api.localhost:28000"
+        );
+        for (hostport, label, expected) in [
+            ("quake.se", "", ".quake.se"),
+            ("quake.se", "eu.west", "eu.west.quake.se"),
+            ("10.10.10.10", "eu", "eu.10.10.10.10"),
+            ("::1", "eu", "eu.::1"),
+        ] {
+            assert_eq!(
+                HostPort::new(hostport, 28000)?.with_subdomain(label),
+                Err(HostPortParseError::InvalidHost(expected.to_string())),
+                "{hostport} {label}"
+            );
+        }
+        assert_eq!(
+            HostPort::new("quake.se", 28000)?.with_subdomain("eu_west"),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '_',
+                position: 2
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "psl")]
+    fn test_public_suffix() -> Result<()> {
+        for (value, effective_tld, registrable_domain) in [
+            ("a.b.example.co.uk", Some("co.uk"), Some("example.co.uk")),
+            ("Example.CO.UK", Some("CO.UK"), Some("Example.CO.UK")),
+            ("qw.quake.se", Some("se"), Some("quake.se")),
+            ("co.uk", Some("co.uk"), None),
+            ("localhost", None, None),
+        ] {
+            let domain = Domain::new(value)?;
+            assert_eq!(domain.effective_tld(), effective_tld);
+            assert_eq!(domain.registrable_domain(), registrable_domain);
+
+            let hostport = HostPort::new(value, 28000)?;
+            assert_eq!(hostport.effective_tld(), effective_tld);
+            assert_eq!(hostport.registrable_domain(), registrable_domain);
+        }
+
+        let hostport = HostPort::new("10.10.10.10", 28000)?;
+        assert_eq!(hostport.effective_tld(), None);
+        assert_eq!(hostport.registrable_domain(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hostport_domain() -> Result<()> {
+        let hostport = HostPort::new("quake.se", 28000)?;
+        assert_eq!(hostport.domain(), Some(Domain::new("quake.se")?));
+        assert_eq!(HostPort::new("::1", 28000)?.domain(), None);
+        Ok(())
+    }
+}
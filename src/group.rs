@@ -0,0 +1,113 @@
+//! Grouping of many `HostPort`s by host.
+
+use crate::HostPort;
+use std::collections::HashMap;
+
+/// Groups `HostPort`s by canonical host (see [`HostPort::canonicalize`]),
+/// collecting the ports of each host in input order.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostPort, group_by_host};
+///
+/// let servers = ["quake.se:28000", "10.10.10.10:27500", "Quake.se:28001"]
+///     .map(|value| HostPort::try_from(value).unwrap());
+/// let groups = group_by_host(servers);
+/// assert_eq!(groups["quake.se"], vec![28000, 28001]);
+/// assert_eq!(groups["10.10.10.10"], vec![27500]);
+/// ```
+pub fn group_by_host(hostports: impl IntoIterator<Item = HostPort>) -> HashMap<String, Vec<u16>> {
+    let mut groups: HashMap<String, Vec<u16>> = HashMap::new();
+    for hostport in hostports {
+        let canonical = hostport.canonicalize();
+        groups
+            .entry(canonical.host().to_string())
+            .or_default()
+            .push(canonical.port());
+    }
+    groups
+}
+
+/// Groups `HostPort`s by registrable domain (see
+/// [`HostPort::registrable_domain`]), e.g. `eu.quake.se:28000` and
+/// `us.quake.se:28000` under `quake.se`. IP addresses, public suffixes and
+/// single-label network aliases are grouped by canonical host.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostPort, group_by_registrable_domain};
+///
+/// let servers = ["eu.quake.se:28000", "us.quake.se:28000", "localhost:27500"]
+///     .map(|value| HostPort::try_from(value).unwrap());
+/// let groups = group_by_registrable_domain(servers);
+/// assert_eq!(groups["quake.se"], vec!["eu.quake.se:28000", "us.quake.se:28000"]);
+/// assert_eq!(groups["localhost"], vec!["localhost:27500"]);
+/// ```
+#[cfg(feature = "psl")]
+pub fn group_by_registrable_domain(
+    hostports: impl IntoIterator<Item = HostPort>,
+) -> HashMap<String, Vec<HostPort>> {
+    let mut groups: HashMap<String, Vec<HostPort>> = HashMap::new();
+    for hostport in hostports {
+        let canonical = hostport.canonicalize();
+        let key = canonical
+            .registrable_domain()
+            .unwrap_or(canonical.host())
+            .to_string();
+        groups.entry(key).or_default().push(hostport);
+    }
+    groups
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    fn hostports(values: &[&str]) -> Result<Vec<HostPort>> {
+        Ok(values
+            .iter()
+            .map(|value| HostPort::try_from(*value))
+            .collect::<Result<_, _>>()?)
+    }
+
+    #[test]
+    fn test_group_by_host() -> Result<()> {
+        let groups = group_by_host(hostports(&[
+            "quake.se:28000",
+            "[2001:DB8::1]:27500",
+            "QUAKE.se:28001",
+            "[2001:db8:0::1]:27501",
+            "quake.se:28000",
+        ])?);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["quake.se"], vec![28000, 28001, 28000]);
+        assert_eq!(groups["2001:db8::1"], vec![27500, 27501]);
+
+        assert!(group_by_host(Vec::new()).is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "psl")]
+    #[test]
+    fn test_group_by_registrable_domain() -> Result<()> {
+        let groups = group_by_registrable_domain(hostports(&[
+            "eu.quake.se:28000",
+            "Quake.se:27500",
+            "a.example.co.uk:80",
+            "co.uk:80",
+            "10.10.10.10:28000",
+        ])?);
+        assert_eq!(groups.len(), 4);
+        assert_eq!(
+            groups["quake.se"],
+            vec!["eu.quake.se:28000", "Quake.se:27500"]
+        );
+        assert_eq!(groups["example.co.uk"], vec!["a.example.co.uk:80"]);
+        assert_eq!(groups["co.uk"], vec!["co.uk:80"]);
+        assert_eq!(groups["10.10.10.10"], vec!["10.10.10.10:28000"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,447 @@
+//! Host without a port.
+
+use crate::validate::HostRules;
+use crate::{HostKind, HostPortParseError, host_to_ascii, invalid_host_error, validate};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Represents a validated host: hostname, network alias, or IP address.
+///
+/// IPv6 hosts are stored without brackets. Hosts are compared, ordered and
+/// hashed ignoring ASCII case, as in DNS, and IPv6 hosts by address and zone,
+/// so `0::1` equals `::1`.
+#[derive(Clone, Debug)]
+pub struct Host(pub(crate) Cow<'static, str>);
+
+impl Host {
+    /// The `localhost` network alias.
+    pub const LOCALHOST: Host = Host(Cow::Borrowed("localhost"));
+
+    /// The IPv4 loopback address, `127.0.0.1`.
+    pub const LOOPBACK_V4: Host = Host(Cow::Borrowed("127.0.0.1"));
+
+    /// The IPv6 loopback address, `::1`.
+    pub const LOOPBACK_V6: Host = Host(Cow::Borrowed("::1"));
+
+    /// The IPv4 unspecified address, `0.0.0.0`.
+    pub const UNSPECIFIED_V4: Host = Host(Cow::Borrowed("0.0.0.0"));
+
+    /// The IPv6 unspecified address, `::`.
+    pub const UNSPECIFIED_V6: Host = Host(Cow::Borrowed("::"));
+
+    /// Creates a new `Host` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Host;
+    ///
+    /// let host = Host::new("quake.se").unwrap();
+    /// assert_eq!(host.as_str(), "quake.se");
+    /// assert!(Host::new("quake|se").is_err());
+    /// ```
+    pub fn new(host: &str) -> Result<Host, HostPortParseError> {
+        Host::with_rules(host, &HostRules::default())
+    }
+
+    /// Creates a new `Host` instance, validated with the given rules.
+    pub(crate) fn with_rules(host: &str, rules: &HostRules) -> Result<Host, HostPortParseError> {
+        let ascii_host = host_to_ascii(host);
+        if !rules.is_valid_host(&ascii_host) {
+            return Err(invalid_host_error(host, rules));
+        }
+        Ok(Host(Cow::Owned(ascii_host.into_owned())))
+    }
+
+    /// Returns the host as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the kind of host.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{Host, HostKind};
+    ///
+    /// assert_eq!(Host::new("10.10.10.10").unwrap().kind(), HostKind::Ipv4);
+    /// assert_eq!(Host::new("::1").unwrap().kind(), HostKind::Ipv6);
+    /// assert_eq!(Host::new("quake.se").unwrap().kind(), HostKind::DomainName);
+    /// assert_eq!(Host::new("localhost").unwrap().kind(), HostKind::Alias);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> HostKind {
+        match self.ip() {
+            Some(IpAddr::V4(_)) => HostKind::Ipv4,
+            Some(IpAddr::V6(_)) => HostKind::Ipv6,
+            None if self.0.contains('.') => HostKind::DomainName,
+            None => HostKind::Alias,
+        }
+    }
+
+    /// Returns the IP address if the host is an IP literal. The zone identifier
+    /// of a scoped IPv6 host is not included.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Host;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let host = Host::new("10.10.10.10").unwrap();
+    /// assert_eq!(host.ip(), Some(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10))));
+    /// assert_eq!(Host::new("quake.se").unwrap().ip(), None);
+    /// ```
+    #[must_use]
+    pub fn ip(&self) -> Option<IpAddr> {
+        let addr = self
+            .0
+            .split_once('%')
+            .map_or(self.as_str(), |(addr, _)| addr);
+        addr.parse::<IpAddr>().ok()
+    }
+
+    /// Returns the zone identifier of a scoped IPv6 host, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Host;
+    ///
+    /// assert_eq!(Host::new("fe80::1%eth0").unwrap().zone(), Some("eth0"));
+    /// assert_eq!(Host::new("fe80::1").unwrap().zone(), None);
+    /// ```
+    #[must_use]
+    pub fn zone(&self) -> Option<&str> {
+        self.0.split_once('%').map(|(_, zone)| zone)
+    }
+}
+
+/// Parses a `Host`, accepting IPv6 hosts with or without brackets.
+///
+/// # Examples
+/// ```
+/// use hostport::Host;
+///
+/// let host = Host::try_from("[::1]").unwrap();
+/// assert_eq!(host.as_str(), "::1");
+/// ```
+impl TryFrom<&str> for Host {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            Some(host) if validate::is_valid_scoped_ipv6(host) => Host::new(host),
+            Some(host) => Err(HostPortParseError::InvalidHost(host.to_string())),
+            None => Host::new(value),
+        }
+    }
+}
+
+impl FromStr for Host {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Host::try_from(value)
+    }
+}
+
+/// Compares hosts ignoring ASCII case, so `Quake.SE` equals `quake.se`. IPv6
+/// hosts are compared by address and zone (ignoring ASCII case), so
+/// `2001:DB8:0::1` equals `2001:db8::1`.
+///
+/// # Examples
+/// ```
+/// use hostport::Host;
+///
+/// assert_eq!(Host::new("Quake.SE").unwrap(), Host::new("quake.se").unwrap());
+/// assert_eq!(Host::new("0::1").unwrap(), Host::new("::1").unwrap());
+/// ```
+impl PartialEq for Host {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.ipv6_and_zone(), other.ipv6_and_zone()) {
+            (Some((ip, zone)), Some((other_ip, other_zone))) => {
+                ip == other_ip && eq_zone_ignore_case(zone, other_zone)
+            }
+            _ => self.0.eq_ignore_ascii_case(&other.0),
+        }
+    }
+}
+
+impl Eq for Host {}
+
+impl Hash for Host {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let name = match self.ipv6_and_zone() {
+            Some((ip, zone)) => {
+                state.write(&ip.octets());
+                zone.unwrap_or_default()
+            }
+            None => &self.0,
+        };
+        for byte in name.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+}
+
+impl Host {
+    /// Returns the address and zone identifier of an IPv6 host. IPv4 hosts
+    /// have a single textual form and are compared as text.
+    fn ipv6_and_zone(&self) -> Option<(Ipv6Addr, Option<&str>)> {
+        if !self.0.contains(':') {
+            return None;
+        }
+        let (addr, zone) = match self.0.split_once('%') {
+            Some((addr, zone)) => (addr, Some(zone)),
+            None => (self.as_str(), None),
+        };
+        Some((addr.parse().ok()?, zone))
+    }
+}
+
+fn eq_zone_ignore_case(zone: Option<&str>, other: Option<&str>) -> bool {
+    zone.unwrap_or_default()
+        .eq_ignore_ascii_case(other.unwrap_or_default())
+}
+
+/// Orders IP hosts numerically (IPv4 before IPv6) and before hostnames, which
+/// are ordered lexicographically, ignoring ASCII case.
+///
+/// # Examples
+/// ```
+/// use hostport::Host;
+///
+/// let mut hosts = ["quake.se", "10.10.0.1", "::1", "10.2.0.1"].map(|host| Host::new(host).unwrap());
+/// hosts.sort();
+/// assert_eq!(hosts.map(|host| host.to_string()), ["10.2.0.1", "10.10.0.1", "[::1]", "quake.se"]);
+/// ```
+impl Ord for Host {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.ip(), other.ip()) {
+            (Some(ip), Some(other_ip)) => ip.cmp(&other_ip).then_with(|| {
+                cmp_bytes_ignore_case(
+                    self.zone().unwrap_or_default(),
+                    other.zone().unwrap_or_default(),
+                )
+            }),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => cmp_bytes_ignore_case(&self.0, &other.0),
+        }
+    }
+}
+
+impl PartialOrd for Host {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn cmp_bytes_ignore_case(value: &str, other: &str) -> Ordering {
+    let lowercase = |byte: u8| byte.to_ascii_lowercase();
+    value
+        .bytes()
+        .map(lowercase)
+        .cmp(other.bytes().map(lowercase))
+}
+
+/// Returns [`Host::LOCALHOST`], as an empty host would be invalid.
+impl Default for Host {
+    fn default() -> Self {
+        Host::LOCALHOST
+    }
+}
+
+/// Formats the `Host`. IPv6 hosts are enclosed in brackets and rendered in
+/// canonical (RFC 5952) form.
+///
+/// # Examples
+/// ```
+/// use hostport::Host;
+///
+/// assert_eq!(Host::new("quake.se").unwrap().to_string(), "quake.se");
+/// assert_eq!(Host::new("2001:0DB8::0001").unwrap().to_string(), "[2001:db8::1]");
+/// assert_eq!(format!("{:>10}", Host::new("quake.se").unwrap()), "  quake.se");
+/// ```
+impl Display for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.ip(), self.zone()) {
+            (Some(IpAddr::V6(ip)), Some(zone)) => f.pad(&format!("[{ip}%{zone}]")),
+            (Some(IpAddr::V6(ip)), None) => f.pad(&format!("[{ip}]")),
+            _ => f.pad(&self.0),
+        }
+    }
+}
+
+impl AsRef<str> for Host {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Ipv4Addr> for Host {
+    fn from(ip: Ipv4Addr) -> Self {
+        Host(ip.to_string().into())
+    }
+}
+
+impl From<Ipv6Addr> for Host {
+    fn from(ip: Ipv6Addr) -> Self {
+        Host(ip.to_string().into())
+    }
+}
+
+impl From<IpAddr> for Host {
+    fn from(ip: IpAddr) -> Self {
+        Host(ip.to_string().into())
+    }
+}
+
+impl PartialEq<&str> for Host {
+    fn eq(&self, other: &&str) -> bool {
+        Host::try_from(*other).is_ok_and(|other| *self == other)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        assert_eq!(Host::new("quake.se")?.as_str(), "quake.se");
+        assert_eq!(Host::new("::1")?.as_str(), "::1");
+        assert_eq!(
+            Host::new("quake|se"),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '|',
+                position: 5
+            })
+        );
+        assert_eq!(
+            Host::new("[::1]"),
+            Err(HostPortParseError::InvalidHost("[::1]".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        assert_eq!(Host::try_from("quake.se")?, Host::new("quake.se")?);
+        assert_eq!(Host::try_from("[::1]")?, Host::new("::1")?);
+        assert_eq!(Host::try_from("::1")?, Host::new("::1")?);
+        assert_eq!(
+            Host::try_from("[quake.se]"),
+            Err(HostPortParseError::InvalidHost("quake.se".to_string()))
+        );
+        assert_eq!("localhost".parse::<Host>()?, "localhost");
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(Host::new("quake.se")?.to_string(), "quake.se");
+        assert_eq!(Host::new("10.10.10.10")?.to_string(), "10.10.10.10");
+        assert_eq!(Host::new("::0001")?.to_string(), "[::1]");
+        assert_eq!(Host::new("fe80::1%eth0")?.to_string(), "[fe80::1%eth0]");
+        Ok(())
+    }
+
+    #[test]
+    fn test_constants() -> Result<()> {
+        assert_eq!(Host::LOCALHOST, Host::new("localhost")?);
+        assert_eq!(Host::default(), Host::LOCALHOST);
+        assert_eq!(Host::LOOPBACK_V4, Host::from(Ipv4Addr::LOCALHOST));
+        assert_eq!(Host::LOOPBACK_V6, Host::from(Ipv6Addr::LOCALHOST));
+        assert_eq!(Host::UNSPECIFIED_V4, Host::from(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(Host::UNSPECIFIED_V6, Host::from(Ipv6Addr::UNSPECIFIED));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ord() -> Result<()> {
+        let mut hosts: Vec<Host> = [
+            "quake.se",
+            "10.10.0.1",
+            "fe80::1%eth1",
+            "::1",
+            "10.2.0.1",
+            "fe80::1",
+            "fe80::1%eth0",
+            "0::1",
+            "localhost",
+            "9.9.9.9",
+        ]
+        .into_iter()
+        .map(Host::new)
+        .collect::<Result<_, _>>()?;
+        hosts.sort();
+        assert_eq!(
+            hosts.iter().map(Host::as_str).collect::<Vec<_>>(),
+            [
+                "9.9.9.9",
+                "10.2.0.1",
+                "10.10.0.1",
+                "::1",
+                "0::1",
+                "fe80::1",
+                "fe80::1%eth0",
+                "fe80::1%eth1",
+                "localhost",
+                "quake.se",
+            ]
+        );
+        assert_eq!(Host::new("::1")?, Host::new("0::1")?);
+        assert_eq!(Host::new("::1")?.cmp(&Host::new("0::1")?), Ordering::Equal);
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_and_hash_ignore_case() -> Result<()> {
+        use std::collections::HashSet;
+
+        let upper = Host::new("Quake.SE")?;
+        let lower = Host::new("quake.se")?;
+        assert_eq!(upper, lower);
+        assert_eq!(upper.cmp(&lower), Ordering::Equal);
+        assert_eq!(upper.as_str(), "Quake.SE");
+        assert_eq!(Host::new("FE80::1%eth0")?, Host::new("fe80::1%eth0")?);
+        assert_ne!(Host::new("quake.se")?, Host::new("quake.com")?);
+        assert_ne!(Host::new("ab")?, Host::new("a")?);
+
+        let set: HashSet<Host> = [upper, lower, Host::new("QUAKE.se")?].into();
+        assert_eq!(set.len(), 1);
+
+        let set: HashSet<Host> = [
+            Host::new("2001:db8::1")?,
+            Host::new("2001:DB8:0:0::1")?,
+            Host::new("2001:0db8::0001")?,
+        ]
+        .into();
+        assert_eq!(set.len(), 1);
+        assert_ne!(Host::new("fe80::1%eth0")?, Host::new("fe80::1")?);
+        assert_eq!(Host::new("FE80::1%ETH0")?, Host::new("fe80:0::1%eth0")?);
+        assert_ne!(Host::new("::ffff:10.0.0.1")?, Host::new("10.0.0.1")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ip() {
+        assert_eq!(Host::from(Ipv4Addr::LOCALHOST).as_str(), "127.0.0.1");
+        assert_eq!(Host::from(Ipv6Addr::LOCALHOST).as_str(), "::1");
+        assert_eq!(
+            Host::from(IpAddr::from([10, 0, 0, 1])).kind(),
+            HostKind::Ipv4
+        );
+    }
+}
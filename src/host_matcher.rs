@@ -0,0 +1,187 @@
+//! Glob and regex matching over host and port.
+
+use crate::HostPort;
+
+#[cfg(feature = "regex")]
+use crate::HostPortParseError;
+
+/// Matches `HostPort` values against a glob pattern, or a regular expression
+/// with the `regex` feature, e.g. for filtering large server lists.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostMatcher, HostPort};
+///
+/// let matcher = HostMatcher::glob("eu-*.quake.se:275??");
+/// assert!(matcher.matches(&HostPort::new("eu-1.quake.se", 27500).unwrap()));
+/// assert!(!matcher.matches(&HostPort::new("us-1.quake.se", 27500).unwrap()));
+/// assert!(!matcher.matches(&HostPort::new("eu-1.quake.se", 28000).unwrap()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HostMatcher {
+    pattern: Pattern,
+}
+
+#[derive(Clone, Debug)]
+enum Pattern {
+    Glob {
+        pattern: String,
+        with_port: bool,
+    },
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl HostMatcher {
+    /// Creates a matcher from a case-insensitive glob pattern, where `*` matches
+    /// any sequence of characters and `?` matches a single character.
+    ///
+    /// Patterns containing `:` are matched against the `host:port` rendering
+    /// (with IPv6 hosts in brackets), other patterns against the host only.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostMatcher, HostPort};
+    ///
+    /// let hostport = HostPort::new("qw.quake.se", 28000).unwrap();
+    /// assert!(HostMatcher::glob("*.quake.se").matches(&hostport));
+    /// assert!(HostMatcher::glob("*:28???").matches(&hostport));
+    /// assert!(!HostMatcher::glob("*:27???").matches(&hostport));
+    /// ```
+    #[must_use]
+    pub fn glob(pattern: &str) -> HostMatcher {
+        HostMatcher {
+            pattern: Pattern::Glob {
+                pattern: pattern.to_string(),
+                with_port: pattern.contains(':'),
+            },
+        }
+    }
+
+    /// Creates a matcher from a regular expression, matched against the whole
+    /// `host:port` rendering (with IPv6 hosts in brackets).
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostMatcher, HostPort};
+    ///
+    /// let matcher = HostMatcher::regex(r"(eu|us)-\d+\.quake\.se:2750[0-9]").unwrap();
+    /// assert!(matcher.matches(&HostPort::new("eu-12.quake.se", 27501).unwrap()));
+    /// assert!(!matcher.matches(&HostPort::new("eu-12.quake.se", 27510).unwrap()));
+    /// assert!(HostMatcher::regex("(").is_err());
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<HostMatcher, HostPortParseError> {
+        let regex = regex::Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|_| HostPortParseError::InvalidPattern(pattern.to_string()))?;
+        Ok(HostMatcher {
+            pattern: Pattern::Regex(regex),
+        })
+    }
+
+    /// Returns `true` if the `HostPort` matches.
+    #[must_use]
+    pub fn matches(&self, hostport: &HostPort) -> bool {
+        match &self.pattern {
+            Pattern::Glob {
+                pattern,
+                with_port: true,
+            } => glob_match(pattern.as_bytes(), hostport.to_string().as_bytes()),
+            Pattern::Glob {
+                pattern,
+                with_port: false,
+            } => glob_match(pattern.as_bytes(), hostport.host().as_bytes()),
+            #[cfg(feature = "regex")]
+            Pattern::Regex(regex) => regex.is_match(&hostport.to_string()),
+        }
+    }
+}
+
+/// Matches a case-insensitive glob pattern with `*` and `?` wildcards.
+fn glob_match(pattern: &[u8], value: &[u8]) -> bool {
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c.eq_ignore_ascii_case(&value[v]) => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                // let the last `*` consume one more character
+                Some((star, start)) => {
+                    backtrack = Some((star, start + 1));
+                    p = star + 1;
+                    v = start + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_glob_match() {
+        // match
+        assert!(glob_match(b"", b""));
+        assert!(glob_match(b"*", b""));
+        assert!(glob_match(b"*", b"quake.se"));
+        assert!(glob_match(b"quake.se", b"QUAKE.se"));
+        assert!(glob_match(b"*.se", b"qw.quake.se"));
+        assert!(glob_match(b"q?ake.*", b"quake.se"));
+        assert!(glob_match(b"*a*a*", b"banana"));
+        assert!(glob_match(b"**.se", b"quake.se"));
+
+        // no match
+        assert!(!glob_match(b"", b"a"));
+        assert!(!glob_match(b"?", b""));
+        assert!(!glob_match(b"*.se", b"quake.com"));
+        assert!(!glob_match(b"quake", b"quake.se"));
+        assert!(!glob_match(b"*a*b", b"banana"));
+    }
+
+    #[test]
+    fn test_glob() -> Result<()> {
+        let hostport = HostPort::try_from("eu-1.quake.se:27500")?;
+        assert!(HostMatcher::glob("eu-*.quake.se:275??").matches(&hostport));
+        assert!(HostMatcher::glob("eu-*").matches(&hostport));
+        assert!(HostMatcher::glob("*:27500").matches(&hostport));
+        assert!(!HostMatcher::glob("eu-*.quake.se:275?").matches(&hostport));
+        assert!(!HostMatcher::glob("us-*").matches(&hostport));
+
+        let hostport = HostPort::try_from("[::1]:27500")?;
+        assert!(HostMatcher::glob("[::1]:*").matches(&hostport));
+        assert!(!HostMatcher::glob("::1").matches(&hostport));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_regex() -> Result<()> {
+        let matcher = HostMatcher::regex(r"eu-\d+\.quake\.se:275\d\d")?;
+        assert!(matcher.matches(&HostPort::try_from("eu-1.quake.se:27500")?));
+        assert!(!matcher.matches(&HostPort::try_from("xeu-1.quake.se:27500")?));
+        assert!(!matcher.matches(&HostPort::try_from("eu-1.quake.se:2750")?));
+        assert!(
+            !HostMatcher::regex(r"quake\.se:27500")?
+                .matches(&HostPort::try_from("eu-1.quake.se:27500")?)
+        );
+        assert_eq!(
+            HostMatcher::regex("[").unwrap_err(),
+            HostPortParseError::InvalidPattern("[".to_string())
+        );
+        Ok(())
+    }
+}
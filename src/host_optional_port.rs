@@ -0,0 +1,249 @@
+//! Host with an optional port.
+
+use crate::{Host, HostPort, HostPortParseError, validate};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Represents a host with an optional port, distinguishing "no port specified"
+/// from port `0`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HostOptionalPort {
+    /// Hostname, network alias, or IP address.
+    pub(crate) host: Host,
+
+    /// Port number, if specified.
+    pub(crate) port: Option<u16>,
+}
+
+impl HostOptionalPort {
+    /// Creates a new `HostOptionalPort` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostOptionalPort;
+    ///
+    /// let value = HostOptionalPort::new("quake.se", None).unwrap();
+    /// assert_eq!(value.host(), "quake.se");
+    /// assert_eq!(value.port(), None);
+    /// ```
+    pub fn new(host: &str, port: Option<u16>) -> Result<HostOptionalPort, HostPortParseError> {
+        let host = Host::new(host)?;
+        Ok(Self { host, port })
+    }
+
+    /// Returns the host part.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
+
+    /// Returns the port part, if specified.
+    #[must_use]
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Converts to a `HostPort`, using `default_port` if no port is specified.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostOptionalPort;
+    ///
+    /// let value = HostOptionalPort::try_from("quake.se").unwrap();
+    /// assert_eq!(value.with_default_port(27500), "quake.se:27500");
+    /// ```
+    #[must_use]
+    pub fn with_default_port(self, default_port: u16) -> HostPort {
+        HostPort {
+            host: self.host,
+            port: self.port.unwrap_or(default_port),
+        }
+    }
+
+    /// Returns `true` if both have the same host and port, with an omitted port
+    /// meaning `default_port`, e.g. the scheme default in HTTP-like contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostOptionalPort;
+    ///
+    /// let explicit = HostOptionalPort::try_from("quake.se:80").unwrap();
+    /// let bare = HostOptionalPort::try_from("quake.se").unwrap();
+    /// assert!(explicit.eq_ignore_default_port(&bare, 80));
+    /// assert!(!explicit.eq_ignore_default_port(&bare, 443));
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_default_port(&self, other: &HostOptionalPort, default_port: u16) -> bool {
+        self.host == other.host
+            && self.port.unwrap_or(default_port) == other.port.unwrap_or(default_port)
+    }
+}
+
+/// Parses either `host:port` or a bare host. Bare IPv6 hosts may be given with
+/// or without brackets, an IPv6 host with a port must be bracketed.
+///
+/// # Examples
+/// ```
+/// use hostport::HostOptionalPort;
+///
+/// let value = HostOptionalPort::try_from("quake.se:28000").unwrap();
+/// assert_eq!(value.port(), Some(28000));
+///
+/// let value = HostOptionalPort::try_from("[::1]").unwrap();
+/// assert_eq!(value.host(), "::1");
+/// assert_eq!(value.port(), None);
+/// ```
+impl TryFrom<&str> for HostOptionalPort {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(host) = value
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            if !validate::is_valid_scoped_ipv6(host) {
+                return Err(HostPortParseError::InvalidHost(host.to_string()));
+            }
+            return HostOptionalPort::new(host, None);
+        }
+
+        if value.starts_with('[') || value.matches(':').count() == 1 {
+            return HostPort::try_from(value).map(HostOptionalPort::from);
+        }
+        HostOptionalPort::new(value, None)
+    }
+}
+
+impl FromStr for HostOptionalPort {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        HostOptionalPort::try_from(value)
+    }
+}
+
+impl From<HostPort> for HostOptionalPort {
+    fn from(hostport: HostPort) -> Self {
+        HostOptionalPort {
+            host: hostport.host,
+            port: Some(hostport.port),
+        }
+    }
+}
+
+impl Display for HostOptionalPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.port {
+            Some(port) => write!(
+                f,
+                "{}",
+                HostPort {
+                    host: self.host.clone(),
+                    port,
+                }
+            ),
+            None => write!(f, "{}", self.host),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        {
+            let value = HostOptionalPort::new("quake.se", Some(0))?;
+            assert_eq!(value.host(), "quake.se");
+            assert_eq!(value.port(), Some(0));
+        }
+        {
+            let value = HostOptionalPort::new("quake.se", None)?;
+            assert_eq!(value.host(), "quake.se");
+            assert_eq!(value.port(), None);
+        }
+        assert_eq!(
+            HostOptionalPort::new("_", None),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '_',
+                position: 0,
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        for (value, host, port) in [
+            ("quake.se", "quake.se", None),
+            ("quake.se:0", "quake.se", Some(0)),
+            ("quake.se:28501", "quake.se", Some(28501)),
+            ("10.10.10.10", "10.10.10.10", None),
+            ("::1", "::1", None),
+            ("[::1]", "::1", None),
+            ("[::1]:28501", "::1", Some(28501)),
+        ] {
+            let value = HostOptionalPort::try_from(value)?;
+            assert_eq!(value.host(), host);
+            assert_eq!(value.port(), port);
+        }
+
+        assert_eq!(
+            HostOptionalPort::try_from("[quake.se]"),
+            Err(HostPortParseError::InvalidHost("quake.se".to_string()))
+        );
+        assert_eq!(
+            HostOptionalPort::try_from("quake.se:abc"),
+            Err(HostPortParseError::InvalidPort("abc".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_default_port() -> Result<()> {
+        assert_eq!(
+            HostOptionalPort::try_from("quake.se")?.with_default_port(27500),
+            HostPort::new("quake.se", 27500)?
+        );
+        assert_eq!(
+            HostOptionalPort::try_from("quake.se:0")?.with_default_port(27500),
+            HostPort::new("quake.se", 0)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_ignore_default_port() -> Result<()> {
+        let explicit = HostOptionalPort::try_from("quake.se:80")?;
+        let bare = HostOptionalPort::try_from("quake.se")?;
+        assert!(explicit.eq_ignore_default_port(&bare, 80));
+        assert!(bare.eq_ignore_default_port(&explicit, 80));
+        assert!(bare.eq_ignore_default_port(&bare, 80));
+        assert!(!explicit.eq_ignore_default_port(&bare, 443));
+        assert!(!HostOptionalPort::try_from("qw.quake.se")?.eq_ignore_default_port(&explicit, 80));
+        assert!(!HostOptionalPort::try_from("quake.se:443")?.eq_ignore_default_port(&explicit, 80));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(
+            HostOptionalPort::from_str("quake.se")?.to_string(),
+            "quake.se"
+        );
+        assert_eq!(
+            HostOptionalPort::from_str("quake.se:28501")?.to_string(),
+            "quake.se:28501"
+        );
+        assert_eq!(HostOptionalPort::from_str("::1")?.to_string(), "[::1]");
+        assert_eq!(
+            HostOptionalPort::from_str("[::1]:28501")?.to_string(),
+            "[::1]:28501"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Host patterns with wildcards.
+
+use crate::{Domain, Host, HostKind, HostPort, HostPortParseError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Represents a host pattern for access control, either an exact host or a
+/// single-level wildcard such as `*.quake.se`.
+///
+/// Wildcards follow certificate-style matching rules (RFC 6125): the wildcard
+/// must be the entire leftmost label, it matches exactly one label, and it
+/// must be followed by at least two labels.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostPattern, HostPort};
+///
+/// let pattern = HostPattern::new("*.quake.se").unwrap();
+/// assert!(pattern.matches(&HostPort::new("qw.quake.se", 28000).unwrap()));
+/// assert!(!pattern.matches(&HostPort::new("quake.se", 28000).unwrap()));
+/// assert!(!pattern.matches(&HostPort::new("a.qw.quake.se", 28000).unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HostPattern {
+    /// Matches the host exactly (case-insensitive).
+    Exact(Host),
+
+    /// Matches any single label followed by the domain.
+    Wildcard(Domain),
+}
+
+impl HostPattern {
+    /// Creates a new `HostPattern` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPattern;
+    ///
+    /// assert!(HostPattern::new("*.quake.se").is_ok());
+    /// assert!(HostPattern::new("quake.se").is_ok());
+    /// assert!(HostPattern::new("*.se").is_err());
+    /// assert!(HostPattern::new("q*.quake.se").is_err());
+    /// ```
+    pub fn new(pattern: &str) -> Result<HostPattern, HostPortParseError> {
+        let invalid = || HostPortParseError::InvalidHost(pattern.to_string());
+
+        match pattern.strip_prefix("*.") {
+            Some(domain) if domain.contains('.') && !domain.contains('*') => Ok(
+                HostPattern::Wildcard(Domain::new(domain).map_err(|_| invalid())?),
+            ),
+            Some(_) => Err(invalid()),
+            None if pattern.contains('*') => Err(invalid()),
+            None => Ok(HostPattern::Exact(Host::new(pattern)?)),
+        }
+    }
+
+    /// Returns `true` if the host of the `HostPort` matches the pattern. The port
+    /// is ignored.
+    #[must_use]
+    pub fn matches(&self, hostport: &HostPort) -> bool {
+        let host = hostport.as_host();
+        match self {
+            HostPattern::Exact(exact) => match (exact.ip(), host.ip()) {
+                (Some(a), Some(b)) => a == b && exact.zone() == host.zone(),
+                _ => exact.as_str().eq_ignore_ascii_case(host.as_str()),
+            },
+            HostPattern::Wildcard(domain) => {
+                host.kind() == HostKind::DomainName
+                    && host
+                        .as_str()
+                        .split_once('.')
+                        .is_some_and(|(_, rest)| rest.eq_ignore_ascii_case(domain.as_str()))
+            }
+        }
+    }
+}
+
+impl HostPort {
+    /// Returns `true` if the host matches a DNS name presented in a certificate,
+    /// following RFC 6125: case-insensitive exact matching, or a wildcard as the
+    /// complete leftmost label matching exactly one label (`*.quake.se`). A trailing
+    /// dot is ignored. IP hosts never match, since certificates present IP addresses
+    /// separately.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("qw.quake.se", 28000).unwrap();
+    /// assert!(hostport.matches_dns_name("qw.quake.se"));
+    /// assert!(hostport.matches_dns_name("*.quake.se"));
+    /// assert!(!hostport.matches_dns_name("*.se"));
+    /// assert!(!hostport.matches_dns_name("q*.quake.se"));
+    /// ```
+    #[must_use]
+    pub fn matches_dns_name(&self, presented: &str) -> bool {
+        if self.ip().is_some() {
+            return false;
+        }
+        HostPattern::new(presented.strip_suffix('.').unwrap_or(presented))
+            .is_ok_and(|pattern| pattern.matches(self))
+    }
+}
+
+impl TryFrom<&str> for HostPattern {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        HostPattern::new(value)
+    }
+}
+
+impl FromStr for HostPattern {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        HostPattern::new(value)
+    }
+}
+
+impl Display for HostPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HostPattern::Exact(host) => write!(f, "{host}"),
+            HostPattern::Wildcard(domain) => write!(f, "*.{domain}"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        assert_eq!(
+            HostPattern::new("*.quake.se")?,
+            HostPattern::Wildcard(Domain::new("quake.se")?)
+        );
+        assert_eq!(
+            HostPattern::new("quake.se")?,
+            HostPattern::Exact(Host::new("quake.se")?)
+        );
+        for pattern in [
+            "*",
+            "*.",
+            "*.se",
+            "*.*.se",
+            "q*.quake.se",
+            "qw.*.se",
+            "*.10.10.10.10",
+        ] {
+            assert_eq!(
+                HostPattern::new(pattern),
+                Err(HostPortParseError::InvalidHost(pattern.to_string()))
+            );
+        }
+        assert!(HostPattern::new("quake|se").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let matches = |pattern: &str, value: &str| -> Result<bool> {
+            Ok(HostPattern::new(pattern)?.matches(&HostPort::try_from(value)?))
+        };
+
+        // wildcard
+        assert!(matches("*.quake.se", "qw.quake.se:28000")?);
+        assert!(matches("*.quake.se", "QW.Quake.SE:28000")?);
+        assert!(!matches("*.quake.se", "quake.se:28000")?);
+        assert!(!matches("*.quake.se", "a.qw.quake.se:28000")?);
+        assert!(!matches("*.quake.se", "qw.notquake.se:28000")?);
+
+        // exact
+        assert!(matches("quake.se", "QUAKE.se:28000")?);
+        assert!(!matches("quake.se", "qw.quake.se:28000")?);
+        assert!(matches("10.10.10.10", "10.10.10.10:28000")?);
+        assert!(matches("::1", "[0::0001]:28000")?);
+        assert!(!matches("fe80::1%eth0", "[fe80::1]:28000")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_dns_name() -> Result<()> {
+        let hostport = HostPort::try_from("qw.Quake.se:28000")?;
+        assert!(hostport.matches_dns_name("qw.quake.se"));
+        assert!(hostport.matches_dns_name("QW.QUAKE.SE."));
+        assert!(hostport.matches_dns_name("*.quake.se"));
+        assert!(hostport.matches_dns_name("*.QUAKE.se."));
+        assert!(!hostport.matches_dns_name("*.se"));
+        assert!(!hostport.matches_dns_name("*.*.se"));
+        assert!(!hostport.matches_dns_name("*w.quake.se"));
+        assert!(!hostport.matches_dns_name("qw.*.se"));
+        assert!(!hostport.matches_dns_name("*"));
+        assert!(!hostport.matches_dns_name(""));
+        assert!(!hostport.matches_dns_name("quake.se"));
+
+        let hostport = HostPort::try_from("a.qw.quake.se:28000")?;
+        assert!(!hostport.matches_dns_name("*.quake.se"));
+
+        let hostport = HostPort::try_from("10.10.10.10:28000")?;
+        assert!(!hostport.matches_dns_name("10.10.10.10"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(HostPattern::new("*.quake.se")?.to_string(), "*.quake.se");
+        assert_eq!(HostPattern::new("::1")?.to_string(), "[::1]");
+        assert_eq!("quake.se".parse::<HostPattern>()?.to_string(), "quake.se");
+        Ok(())
+    }
+}
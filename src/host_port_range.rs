@@ -0,0 +1,244 @@
+//! Host with a range of ports.
+
+use crate::{Host, HostPort, HostPortParseError, parse_port, split_host_port};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Represents a host and an inclusive range of ports, e.g. `quake.se:28000-28010`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HostPortRange {
+    /// Hostname, network alias, or IP address.
+    host: Host,
+
+    /// Inclusive range of port numbers.
+    ports: RangeInclusive<u16>,
+}
+
+impl HostPortRange {
+    /// Creates a new `HostPortRange` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPortRange;
+    ///
+    /// let range = HostPortRange::new("quake.se", 28000..=28010).unwrap();
+    /// assert_eq!(range.host(), "quake.se");
+    /// assert_eq!(range.ports(), 28000..=28010);
+    /// ```
+    pub fn new(
+        host: &str,
+        ports: RangeInclusive<u16>,
+    ) -> Result<HostPortRange, HostPortParseError> {
+        let host = Host::new(host)?;
+        if ports.is_empty() {
+            return Err(HostPortParseError::InvalidPort(format!(
+                "{}-{}",
+                ports.start(),
+                ports.end()
+            )));
+        }
+        Ok(Self { host, ports })
+    }
+
+    /// Returns the host part.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
+
+    /// Returns the range of ports.
+    #[must_use]
+    pub fn ports(&self) -> RangeInclusive<u16> {
+        self.ports.clone()
+    }
+
+    /// Returns `true` if the given `HostPort` has the same host and a port within the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, HostPortRange};
+    ///
+    /// let range = HostPortRange::try_from("quake.se:28000-28010").unwrap();
+    /// assert!(range.contains(&HostPort::new("quake.se", 28005).unwrap()));
+    /// assert!(!range.contains(&HostPort::new("quake.se", 28011).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, hostport: &HostPort) -> bool {
+        self.host == hostport.host && self.ports.contains(&hostport.port)
+    }
+
+    /// Returns an iterator over the `HostPort`s in the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPortRange;
+    ///
+    /// let range = HostPortRange::try_from("quake.se:28000-28002").unwrap();
+    /// let hostports: Vec<_> = range.iter().collect();
+    /// assert_eq!(hostports, vec!["quake.se:28000", "quake.se:28001", "quake.se:28002"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = HostPort> + '_ {
+        self.ports.clone().map(|port| HostPort {
+            host: self.host.clone(),
+            port,
+        })
+    }
+}
+
+/// Parses `host:port` or `host:start-end`.
+///
+/// # Examples
+/// ```
+/// use hostport::HostPortRange;
+///
+/// let range = HostPortRange::try_from("quake.se:28000-28010").unwrap();
+/// assert_eq!(range.ports(), 28000..=28010);
+///
+/// let range = HostPortRange::try_from("[::1]:28000").unwrap();
+/// assert_eq!(range.ports(), 28000..=28000);
+/// ```
+impl TryFrom<&str> for HostPortRange {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (host, ports_str) = split_host_port(value)?;
+        let ports = match ports_str.split_once('-') {
+            Some((start, end)) => parse_port(start)?..=parse_port(end)?,
+            None => {
+                let port = parse_port(ports_str)?;
+                port..=port
+            }
+        };
+        HostPortRange::new(host, ports)
+    }
+}
+
+impl FromStr for HostPortRange {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        HostPortRange::try_from(value)
+    }
+}
+
+impl From<HostPort> for HostPortRange {
+    fn from(hostport: HostPort) -> Self {
+        HostPortRange {
+            host: hostport.host,
+            ports: hostport.port..=hostport.port,
+        }
+    }
+}
+
+impl Display for HostPortRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first = HostPort {
+            host: self.host.clone(),
+            port: *self.ports.start(),
+        };
+        if self.ports.start() == self.ports.end() {
+            write!(f, "{first}")
+        } else {
+            write!(f, "{}-{}", first, self.ports.end())
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        {
+            let range = HostPortRange::new("quake.se", 28000..=28010)?;
+            assert_eq!(range.host(), "quake.se");
+            assert_eq!(range.ports(), 28000..=28010);
+        }
+        assert_eq!(
+            HostPortRange::new("_", 28000..=28010),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '_',
+                position: 0,
+            })
+        );
+        assert_eq!(
+            HostPortRange::new("quake.se", RangeInclusive::new(28010, 28000)),
+            Err(HostPortParseError::InvalidPort("28010-28000".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        assert_eq!(
+            HostPortRange::try_from("quake.se:28000-28010")?,
+            HostPortRange::new("quake.se", 28000..=28010)?
+        );
+        assert_eq!(
+            HostPortRange::try_from("[::1]:28000-28010")?,
+            HostPortRange::new("::1", 28000..=28010)?
+        );
+        assert_eq!(
+            HostPortRange::try_from("quake.se:28000")?,
+            HostPortRange::new("quake.se", 28000..=28000)?
+        );
+        assert_eq!(
+            HostPortRange::try_from("quake.se:28000-abc"),
+            Err(HostPortParseError::InvalidPort("abc".to_string()))
+        );
+        assert_eq!(
+            HostPortRange::try_from("quake.se:28010-28000"),
+            Err(HostPortParseError::InvalidPort("28010-28000".to_string()))
+        );
+        assert_eq!(
+            HostPortRange::try_from("quake.se"),
+            Err(HostPortParseError::InvalidFormat)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains() -> Result<()> {
+        let range = HostPortRange::try_from("quake.se:28000-28010")?;
+        assert!(range.contains(&HostPort::new("quake.se", 28000)?));
+        assert!(range.contains(&HostPort::new("quake.se", 28010)?));
+        assert!(!range.contains(&HostPort::new("quake.se", 27999)?));
+        assert!(!range.contains(&HostPort::new("quake.se", 28011)?));
+        assert!(!range.contains(&HostPort::new("quake.com", 28005)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter() -> Result<()> {
+        let range = HostPortRange::try_from("quake.se:65534-65535")?;
+        assert_eq!(
+            range.iter().collect::<Vec<_>>(),
+            vec![
+                HostPort::new("quake.se", 65534)?,
+                HostPort::new("quake.se", 65535)?,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        for value in [
+            "quake.se:28000-28010",
+            "[::1]:28000-28010",
+            "quake.se:28000",
+        ] {
+            assert_eq!(HostPortRange::from_str(value)?.to_string(), value);
+        }
+        assert_eq!(
+            HostPortRange::from(HostPort::new("quake.se", 28000)?).to_string(),
+            "quake.se:28000"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+//! IP address classification helpers.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Special-purpose addresses with the same meaning for IPv4 and IPv6, e.g.
+/// `127.0.0.1` and `::1`. IPv4-mapped IPv6 addresses are classified as IPv4.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpecialAddr {
+    Loopback,
+    Unspecified,
+}
+
+fn special_addr(ip: IpAddr) -> Option<SpecialAddr> {
+    let ip = ip.to_canonical();
+    if ip.is_loopback() {
+        Some(SpecialAddr::Loopback)
+    } else if ip.is_unspecified() {
+        Some(SpecialAddr::Unspecified)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_loopback(ip: IpAddr) -> bool {
+    special_addr(ip) == Some(SpecialAddr::Loopback)
+}
+
+pub(crate) fn is_unspecified(ip: IpAddr) -> bool {
+    special_addr(ip) == Some(SpecialAddr::Unspecified)
+}
+
+/// Returns `true` if sockets bound to `a` and `b` on the same port conflict,
+/// with `::` binding both IPv4 and IPv6 (dual-stack, the Linux default) and
+/// `0.0.0.0` binding all IPv4 addresses.
+pub(crate) fn binds_conflict(a: IpAddr, b: IpAddr) -> bool {
+    let (a, b) = (a.to_canonical(), b.to_canonical());
+    let covers = |wildcard: IpAddr, ip: IpAddr| {
+        is_unspecified(wildcard) && (wildcard.is_ipv6() || ip.is_ipv4())
+    };
+    a == b || covers(a, b) || covers(b, a)
+}
+
+pub(crate) fn is_private(ip: IpAddr) -> bool {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => ip.is_private(),
+        IpAddr::V6(ip) => ip.is_unique_local(),
+    }
+}
+
+pub(crate) fn is_link_local(ip: IpAddr) -> bool {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => ip.is_link_local(),
+        IpAddr::V6(ip) => ip.is_unicast_link_local(),
+    }
+}
+
+pub(crate) fn is_global(ip: IpAddr) -> bool {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => is_global_v4(ip),
+        IpAddr::V6(ip) => is_global_v6(ip),
+    }
+}
+
+pub(crate) fn ptr_name(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, d] = ip.octets();
+            format!("{d}.{c}.{b}.{a}.in-addr.arpa")
+        }
+        IpAddr::V6(ip) => {
+            let mut name = String::with_capacity(72);
+            for byte in ip.octets().iter().rev() {
+                name.push_str(&format!("{:x}.{:x}.", byte & 0x0f, byte >> 4));
+            }
+            name.push_str("ip6.arpa");
+            name
+        }
+    }
+}
+
+fn is_global_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    let is_shared = a == 100 && (b & 0b1100_0000) == 64; // 100.64.0.0/10
+    let is_protocol_assignment = a == 192 && b == 0 && c == 0; // 192.0.0.0/24
+    let is_benchmarking = a == 198 && (b & 0b1111_1110) == 18; // 198.18.0.0/15
+    let is_reserved = a >= 240; // 240.0.0.0/4, incl. broadcast
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || is_shared
+        || is_protocol_assignment
+        || is_benchmarking
+        || is_reserved
+        || a == 0)
+}
+
+fn is_global_v6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    let is_documentation = segments[0] == 0x2001 && segments[1] == 0x0db8; // 2001:db8::/32
+    let is_discard_only = segments[0] == 0x0100 && segments[1..4] == [0, 0, 0]; // 100::/64
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        || ip.is_multicast()
+        || is_documentation
+        || is_discard_only)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_special_addr() {
+        for (value, expected) in [
+            ("127.0.0.1", Some(SpecialAddr::Loopback)),
+            ("127.10.10.10", Some(SpecialAddr::Loopback)),
+            ("::1", Some(SpecialAddr::Loopback)),
+            ("::ffff:127.0.0.1", Some(SpecialAddr::Loopback)),
+            ("0.0.0.0", Some(SpecialAddr::Unspecified)),
+            ("::", Some(SpecialAddr::Unspecified)),
+            ("::ffff:0.0.0.0", Some(SpecialAddr::Unspecified)),
+            ("10.10.10.10", None),
+            ("::2", None),
+        ] {
+            assert_eq!(special_addr(ip(value)), expected, "{value}");
+            assert_eq!(
+                is_loopback(ip(value)),
+                expected == Some(SpecialAddr::Loopback)
+            );
+            assert_eq!(
+                is_unspecified(ip(value)),
+                expected == Some(SpecialAddr::Unspecified)
+            );
+        }
+    }
+
+    #[test]
+    fn test_binds_conflict() {
+        // conflict
+        for (a, b) in [
+            ("::", "0.0.0.0"),
+            ("::", "127.0.0.1"),
+            ("::", "::1"),
+            ("::", "::"),
+            ("::", "::ffff:0.0.0.0"),
+            ("0.0.0.0", "127.0.0.1"),
+            ("0.0.0.0", "::ffff:10.10.10.10"),
+            ("::ffff:0.0.0.0", "10.10.10.10"),
+            ("::1", "::1"),
+            ("127.0.0.1", "::ffff:127.0.0.1"),
+        ] {
+            assert!(binds_conflict(ip(a), ip(b)), "{a} {b}");
+            assert!(binds_conflict(ip(b), ip(a)), "{b} {a}");
+        }
+
+        // no conflict
+        for (a, b) in [
+            ("0.0.0.0", "::1"),
+            ("127.0.0.1", "::1"),
+            ("127.0.0.1", "127.0.0.2"),
+            ("::1", "::2"),
+        ] {
+            assert!(!binds_conflict(ip(a), ip(b)), "{a} {b}");
+            assert!(!binds_conflict(ip(b), ip(a)), "{b} {a}");
+        }
+    }
+
+    #[test]
+    fn test_ptr_name() {
+        assert_eq!(ptr_name(ip("10.20.30.40")), "40.30.20.10.in-addr.arpa");
+        assert_eq!(
+            ptr_name(ip("2001:db8::567:89ab")),
+            "b.a.9.8.7.6.5.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa"
+        );
+    }
+
+    #[test]
+    fn test_is_global() {
+        // not global
+        assert!(!is_global(ip("0.0.0.0")));
+        assert!(!is_global(ip("0.1.2.3")));
+        assert!(!is_global(ip("10.10.10.10")));
+        assert!(!is_global(ip("100.64.0.1")));
+        assert!(!is_global(ip("127.0.0.1")));
+        assert!(!is_global(ip("169.254.1.1")));
+        assert!(!is_global(ip("172.16.0.1")));
+        assert!(!is_global(ip("192.0.0.1")));
+        assert!(!is_global(ip("192.0.2.1")));
+        assert!(!is_global(ip("192.168.1.1")));
+        assert!(!is_global(ip("198.18.0.1")));
+        assert!(!is_global(ip("224.0.0.1")));
+        assert!(!is_global(ip("255.255.255.255")));
+        assert!(!is_global(ip("::")));
+        assert!(!is_global(ip("::1")));
+        assert!(!is_global(ip("::ffff:10.10.10.10")));
+        assert!(!is_global(ip("100::1")));
+        assert!(!is_global(ip("2001:db8::1")));
+        assert!(!is_global(ip("fc00::1")));
+        assert!(!is_global(ip("fe80::1")));
+        assert!(!is_global(ip("ff02::1")));
+
+        // global
+        assert!(is_global(ip("1.1.1.1")));
+        assert!(is_global(ip("100.128.0.1")));
+        assert!(is_global(ip("193.10.10.10")));
+        assert!(is_global(ip("::ffff:1.1.1.1")));
+        assert!(is_global(ip("2606:4700::1111")));
+    }
+
+    #[test]
+    fn test_is_private() {
+        assert!(is_private(ip("10.10.10.10")));
+        assert!(is_private(ip("::ffff:192.168.1.1")));
+        assert!(is_private(ip("fd00::1")));
+        assert!(!is_private(ip("1.1.1.1")));
+        assert!(!is_private(ip("2606:4700::1111")));
+    }
+
+    #[test]
+    fn test_is_link_local() {
+        assert!(is_link_local(ip("169.254.1.1")));
+        assert!(is_link_local(ip("fe80::1")));
+        assert!(!is_link_local(ip("10.10.10.10")));
+        assert!(!is_link_local(ip("::1")));
+    }
+
+    #[test]
+    fn test_is_loopback() {
+        assert!(is_loopback(ip("127.0.0.1")));
+        assert!(is_loopback(ip("::1")));
+        assert!(is_loopback(ip("::ffff:127.0.0.1")));
+        assert!(!is_loopback(ip("10.10.10.10")));
+    }
+}
@@ -0,0 +1,391 @@
+//! Compile-time checked `HostPort` literals.
+
+/// Creates a [`HostPort`](crate::HostPort) from a string literal that is
+/// validated and split at compile time, so no `unwrap()` is needed for
+/// known-good constants. The macro can also be used in `const` items.
+///
+/// Accepts a conservative subset of what [`HostPort::try_from`](crate::HostPort::try_from)
+/// accepts: ASCII domains, network aliases, IPv4 addresses and bracketed IPv6
+/// addresses, with a decimal port.
+///
+/// # Examples
+/// ```
+/// use hostport::hostport;
+///
+/// let hostport = hostport!("quake.se:28000");
+/// assert_eq!(hostport.host(), "quake.se");
+/// assert_eq!(hostport.port(), 28000);
+///
+/// assert_eq!(hostport!("[::1]:28000").to_string(), "[::1]:28000");
+///
+/// const MASTER: hostport::HostPort = hostport!("master.quakeworld.nu:27000");
+/// assert_eq!(MASTER.port(), 27000);
+/// ```
+///
+/// Invalid literals fail to compile:
+/// ```compile_fail
+/// use hostport::hostport;
+///
+/// let hostport = hostport!("quake.se:99999");
+/// ```
+#[macro_export]
+macro_rules! hostport {
+    ($value:literal) => {{
+        const HOSTPORT: $crate::HostPort = {
+            assert!(
+                $crate::__is_valid_literal($value),
+                concat!("invalid host:port literal: ", $value)
+            );
+            let (host, port) = $crate::__literal_parts($value);
+            $crate::HostPort::from_static_unchecked(host, port)
+        };
+        HOSTPORT
+    }};
+}
+
+/// Checks if the given `host:port` literal is valid, using only `const`
+/// operations. Every value accepted here is also accepted by `HostPort::try_from`.
+#[doc(hidden)]
+#[must_use]
+pub const fn is_valid_literal(value: &str) -> bool {
+    split_literal(value.as_bytes()).is_some()
+}
+
+/// Splits a valid `host:port` literal into the host, without brackets, and
+/// the port. Panics, i.e. fails to compile in a `const` context, if the
+/// literal is invalid.
+#[doc(hidden)]
+#[must_use]
+pub const fn literal_parts(value: &'static str) -> (&'static str, u16) {
+    let Some((host_start, host_end, port)) = split_literal(value.as_bytes()) else {
+        panic!("invalid host:port literal");
+    };
+    let (head, _) = value.as_bytes().split_at(host_end);
+    let (_, host) = head.split_at(host_start);
+    match std::str::from_utf8(host) {
+        Ok(host) => (host, port),
+        Err(_) => panic!("invalid host:port literal"),
+    }
+}
+
+/// Returns the host range and the port of a valid literal.
+const fn split_literal(bytes: &[u8]) -> Option<(usize, usize, u16)> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let (host_start, host_end, port_start) = if bytes[0] == b'[' {
+        let mut index = 1;
+        while index < bytes.len() && bytes[index] != b']' {
+            index += 1;
+        }
+        if index + 1 >= bytes.len() || bytes[index + 1] != b':' {
+            return None;
+        }
+        if !is_valid_scoped_ipv6(bytes, 1, index) {
+            return None;
+        }
+        (1, index, index + 2)
+    } else {
+        let mut index = bytes.len();
+        while index > 0 && bytes[index - 1] != b':' {
+            index -= 1;
+        }
+        if index == 0 {
+            return None;
+        }
+        let host_end = index - 1;
+        if !is_valid_name(bytes, 0, host_end) {
+            return None;
+        }
+        (0, host_end, index)
+    };
+
+    if host_start >= host_end {
+        return None;
+    }
+    match parse_port(bytes, port_start) {
+        Some(port) => Some((host_start, host_end, port)),
+        None => None,
+    }
+}
+
+const fn parse_port(bytes: &[u8], start: usize) -> Option<u16> {
+    let len = bytes.len() - start;
+    if len == 0 || len > 5 {
+        return None;
+    }
+    let mut value: u32 = 0;
+    let mut index = start;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[index] - b'0') as u32;
+        index += 1;
+    }
+    if value > u16::MAX as u32 {
+        return None;
+    }
+    Some(value as u16)
+}
+
+/// Domain, network alias or IPv4 address, per the default host rules.
+const fn is_valid_name(bytes: &[u8], start: usize, end: usize) -> bool {
+    if end <= start || end - start > 253 {
+        return false;
+    }
+
+    let mut is_ipv4_like = true;
+    let mut dots = 0;
+    let mut index = start;
+    while index < end {
+        match bytes[index] {
+            b'.' => dots += 1,
+            b'0'..=b'9' => {}
+            _ => is_ipv4_like = false,
+        }
+        index += 1;
+    }
+    if is_ipv4_like && dots == 3 {
+        return is_valid_ipv4(bytes, start, end);
+    }
+
+    let mut label_start = start;
+    let mut index = start;
+    while index <= end {
+        if index == end || bytes[index] == b'.' {
+            if !is_valid_label(bytes, label_start, index) {
+                return false;
+            }
+            label_start = index + 1;
+        }
+        index += 1;
+    }
+    true
+}
+
+const fn is_valid_label(bytes: &[u8], start: usize, end: usize) -> bool {
+    if end <= start || end - start > 63 || bytes[start] == b'-' || bytes[end - 1] == b'-' {
+        return false;
+    }
+    let mut is_numeric = true;
+    let mut index = start;
+    while index < end {
+        let byte = bytes[index];
+        if !byte.is_ascii_alphanumeric() && byte != b'-' {
+            return false;
+        }
+        if !byte.is_ascii_digit() {
+            is_numeric = false;
+        }
+        index += 1;
+    }
+    !is_numeric
+}
+
+const fn is_valid_ipv4(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut part_start = start;
+    let mut index = start;
+    while index <= end {
+        if index == end || bytes[index] == b'.' {
+            let len = index - part_start;
+            if len == 0 || len > 3 || (len > 1 && bytes[part_start] == b'0') {
+                return false;
+            }
+            let mut value: u32 = 0;
+            let mut digit = part_start;
+            while digit < index {
+                value = value * 10 + (bytes[digit] - b'0') as u32;
+                digit += 1;
+            }
+            if value > 255 {
+                return false;
+            }
+            part_start = index + 1;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// IPv6 address without embedded IPv4, with an optional zone identifier.
+const fn is_valid_scoped_ipv6(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut addr_end = start;
+    while addr_end < end && bytes[addr_end] != b'%' {
+        addr_end += 1;
+    }
+    if addr_end < end {
+        if addr_end + 1 == end {
+            return false;
+        }
+        let mut index = addr_end + 1;
+        while index < end {
+            let byte = bytes[index];
+            if !byte.is_ascii_alphanumeric() && !matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                return false;
+            }
+            index += 1;
+        }
+    }
+    is_valid_ipv6(bytes, start, addr_end)
+}
+
+const fn is_valid_ipv6(bytes: &[u8], start: usize, end: usize) -> bool {
+    if end <= start {
+        return false;
+    }
+    let mut index = start;
+    let mut groups = 0;
+    let mut has_double_colon = false;
+    if bytes[index] == b':' {
+        if end - start < 2 || bytes[index + 1] != b':' {
+            return false;
+        }
+        has_double_colon = true;
+        index += 2;
+        if index == end {
+            return true;
+        }
+    }
+
+    loop {
+        let group_start = index;
+        while index < end && bytes[index].is_ascii_hexdigit() {
+            index += 1;
+        }
+        let len = index - group_start;
+        if len == 0 || len > 4 {
+            return false;
+        }
+        groups += 1;
+        if index == end {
+            break;
+        }
+        if bytes[index] != b':' {
+            return false;
+        }
+        index += 1;
+        if index == end {
+            return false;
+        }
+        if bytes[index] == b':' {
+            if has_double_colon {
+                return false;
+            }
+            has_double_colon = true;
+            index += 1;
+            if index == end {
+                break;
+            }
+        }
+    }
+
+    if has_double_colon {
+        groups <= 7
+    } else {
+        groups == 8
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::HostPort;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_hostport() {
+        assert_eq!(
+            hostport!("quake.se:28000"),
+            HostPort::new("quake.se", 28000).unwrap()
+        );
+        assert_eq!(hostport!("10.10.10.10:0").port(), 0);
+        assert_eq!(hostport!("[fe80::1%eth0]:65535").host(), "fe80::1%eth0");
+        assert_eq!(
+            hostport!("Quake.SE:28000"),
+            HostPort::try_from("Quake.SE:28000").unwrap()
+        );
+
+        const LOOPBACK: HostPort = hostport!("[::1]:27500");
+        assert_eq!(LOOPBACK, HostPort::loopback_v6(27500));
+    }
+
+    #[test]
+    fn test_literal_parts() {
+        assert_eq!(literal_parts("quake.se:28000"), ("quake.se", 28000));
+        assert_eq!(literal_parts("10.10.10.10:0"), ("10.10.10.10", 0));
+        assert_eq!(
+            literal_parts("[fe80::1%eth0]:65535"),
+            ("fe80::1%eth0", 65535)
+        );
+    }
+
+    #[test]
+    fn test_is_valid_literal() {
+        for value in [
+            "quake.se:28000",
+            "Quake.SE:28000",
+            "localhost:0",
+            "my-server:65535",
+            "1quake.se:28000",
+            "10.10.10.10:28000",
+            "0.0.0.0:80",
+            "[::1]:28000",
+            "[::]:28000",
+            "[2001:db8::1]:27500",
+            "[1::]:80",
+            "[1:2:3:4:5:6:7:8]:80",
+            "[fe80::1%eth0]:8080",
+        ] {
+            assert!(is_valid_literal(value), "{value}");
+            let (host, port) = literal_parts(value);
+            assert_eq!(
+                HostPort::from_static_unchecked(host, port),
+                HostPort::try_from(value).unwrap(),
+                "{value}"
+            );
+        }
+
+        // rejected here, but accepted at runtime is fine
+        for value in [
+            "",
+            ":28000",
+            "quake.se",
+            "quake.se:",
+            "quake.se:99999",
+            "quake.se:+80",
+            "quake.se:28000/",
+            "quake..se:28000",
+            "-quake.se:28000",
+            "quake-.se:28000",
+            "quake_se:28000",
+            "quake.123:28000",
+            "bücher.example:80",
+            "010.10.10.10:80",
+            "256.10.10.10:80",
+            "::1:28000",
+            "[::1:28000",
+            "[::1]28000",
+            "[]:80",
+            "[:1]:80",
+            "[1:]:80",
+            "[1:::2]:80",
+            "[1::2::3]:80",
+            "[12345::1]:80",
+            "[1:2:3:4:5:6:7]:80",
+            "[1:2:3:4:5:6:7:8:9]:80",
+            "[::ffff:10.10.10.10]:80",
+            "[fe80::1%]:80",
+            "[fe80::1%eth 0]:80",
+        ] {
+            assert!(!is_valid_literal(value), "{value}");
+        }
+
+        let long = format!("{}.se:80", "a".repeat(250));
+        assert!(!is_valid_literal(&long));
+        let long_label = format!("{}.se:80", "a".repeat(64));
+        assert!(!is_valid_literal(&long_label));
+    }
+}
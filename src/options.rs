@@ -0,0 +1,425 @@
+//! Options for parsing.
+
+use crate::validate::HostRules;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+/// Options controlling how [`HostPort::parse_with`](crate::HostPort::parse_with)
+/// parses and validates input.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostPort, ParseOptions};
+///
+/// let options = ParseOptions::default()
+///     .allow_underscore(true)
+///     .allow_trailing_dot(true)
+///     .trim_whitespace(true)
+///     .port_range(1024..=65535);
+///
+/// let hostport = HostPort::parse_with(" my_service.internal.:28000 ", &options).unwrap();
+/// assert_eq!(hostport.host(), "my_service.internal");
+/// assert_eq!(hostport.port(), 28000);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) host_rules: HostRules,
+    pub(crate) allow_trailing_dot: bool,
+    pub(crate) trim_whitespace: bool,
+    pub(crate) strip_noise: bool,
+    pub(crate) strip_scheme: bool,
+    pub(crate) reject_zero_port: bool,
+    pub(crate) decode_percent: bool,
+    pub(crate) allow_srv_names: bool,
+    pub(crate) allow_mdns_names: bool,
+    #[cfg(feature = "services")]
+    pub(crate) allow_service_names: bool,
+    pub(crate) port_range: RangeInclusive<u16>,
+    pub(crate) max_length: usize,
+    pub(crate) extra_host_check: Option<HostCheck>,
+}
+
+/// A user-supplied host check, compared by identity.
+#[derive(Clone)]
+pub(crate) struct HostCheck(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl HostCheck {
+    pub(crate) fn check(&self, host: &str) -> bool {
+        (self.0)(host)
+    }
+}
+
+impl fmt::Debug for HostCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HostCheck")
+    }
+}
+
+impl PartialEq for HostCheck {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for HostCheck {}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            host_rules: HostRules::default(),
+            allow_trailing_dot: false,
+            trim_whitespace: false,
+            strip_noise: false,
+            strip_scheme: false,
+            reject_zero_port: false,
+            decode_percent: false,
+            allow_srv_names: false,
+            allow_mdns_names: false,
+            #[cfg(feature = "services")]
+            allow_service_names: false,
+            port_range: 0..=u16::MAX,
+            max_length: usize::MAX,
+            extra_host_check: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Options for parsing values copy-pasted by end users: trims whitespace
+    /// and surrounding noise, and allows a trailing dot on the host.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, ParseOptions};
+    ///
+    /// let hostport = HostPort::parse_with(" \"quake.se:28000\", ", &ParseOptions::lenient()).unwrap();
+    /// assert_eq!(hostport, "quake.se:28000");
+    /// ```
+    #[must_use]
+    pub fn lenient() -> Self {
+        Self::default()
+            .trim_whitespace(true)
+            .strip_noise(true)
+            .allow_trailing_dot(true)
+    }
+
+    /// Allow underscores in host labels, e.g. `my_service.internal`.
+    #[must_use]
+    pub fn allow_underscore(mut self, value: bool) -> Self {
+        self.host_rules.allow_underscore = value;
+        self
+    }
+
+    /// Allow all-numeric host labels, e.g. `123.quake.se`.
+    #[must_use]
+    pub fn allow_numeric_labels(mut self, value: bool) -> Self {
+        self.host_rules.allow_numeric_labels = value;
+        self
+    }
+
+    /// Reject an all-numeric rightmost label (top-level domain), e.g. `quake.123`,
+    /// while still allowing numeric labels elsewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, ParseOptions};
+    ///
+    /// let options = ParseOptions::default()
+    ///     .allow_numeric_labels(true)
+    ///     .allow_numeric_tld(false);
+    /// assert!(HostPort::parse_with("123.quake.se:28000", &options).is_ok());
+    /// assert!(HostPort::parse_with("quake.123:28000", &options).is_err());
+    /// ```
+    #[must_use]
+    pub fn allow_numeric_tld(mut self, value: bool) -> Self {
+        self.host_rules.allow_numeric_tld = value;
+        self
+    }
+
+    /// Validate hosts with the given rules, e.g. [`HostRules::rfc952`] for DNS
+    /// tooling or [`HostRules::lenient`] for game server lists. Replaces any
+    /// previously set host rule options.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, ParseOptions};
+    /// use hostport::validate::HostRules;
+    ///
+    /// let options = ParseOptions::default().host_rules(HostRules::lenient());
+    /// assert!(HostPort::parse_with("my_server.123.internal:28000", &options).is_ok());
+    ///
+    /// let options = ParseOptions::default().host_rules(HostRules::rfc952());
+    /// assert!(HostPort::parse_with("1quake.se:28000", &options).is_err());
+    /// ```
+    #[must_use]
+    pub fn host_rules(mut self, rules: HostRules) -> Self {
+        self.host_rules = rules;
+        self
+    }
+
+    /// Allow the absolute (fully-qualified) form of domains and aliases with a
+    /// trailing dot (`quake.se.`), which is normalized away. IP hosts never
+    /// accept a trailing dot.
+    #[must_use]
+    pub fn allow_trailing_dot(mut self, value: bool) -> Self {
+        self.allow_trailing_dot = value;
+        self
+    }
+
+    /// Trim surrounding whitespace from the input.
+    #[must_use]
+    pub fn trim_whitespace(mut self, value: bool) -> Self {
+        self.trim_whitespace = value;
+        self
+    }
+
+    /// Strip surrounding noise from the input: whitespace, quotes (`"`, `'`, `` ` ``),
+    /// commas and semicolons.
+    #[must_use]
+    pub fn strip_noise(mut self, value: bool) -> Self {
+        self.strip_noise = value;
+        self
+    }
+
+    /// Strip a leading scheme from the input, e.g. `tcp://quake.se:28000`.
+    #[must_use]
+    pub fn strip_scheme(mut self, value: bool) -> Self {
+        self.strip_scheme = value;
+        self
+    }
+
+    /// Reject port `0`, which is usually a configuration error for clients, with
+    /// [`HostPortParseError::PortOutOfRange`](crate::HostPortParseError::PortOutOfRange).
+    #[must_use]
+    pub fn reject_zero_port(mut self, value: bool) -> Self {
+        self.reject_zero_port = value;
+        self
+    }
+
+    /// Decode percent-encoded hosts (RFC 3986 reg-name), e.g. `my%2Dhost`.
+    /// A zone identifier must then be encoded as `%25`, e.g. `[fe80::1%25eth0]`.
+    /// Reg-names decoding to `:`, `[`, `]`, `%` or an empty label are rejected.
+    #[must_use]
+    pub fn decode_percent(mut self, value: bool) -> Self {
+        self.decode_percent = value;
+        self
+    }
+
+    /// Allow SRV record names as host, e.g. `_quake._udp.quake.se`. See
+    /// [`is_valid_srv_name`](crate::validate::is_valid_srv_name).
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().allow_srv_names(true);
+    /// let hostport = HostPort::parse_with("_quake._udp.quake.se:28000", &options).unwrap();
+    /// assert_eq!(hostport.host(), "_quake._udp.quake.se");
+    /// ```
+    #[must_use]
+    pub fn allow_srv_names(mut self, value: bool) -> Self {
+        self.allow_srv_names = value;
+        self
+    }
+
+    /// Allow multicast DNS names as host, which may contain UTF-8 and spaces,
+    /// e.g. `Living Room TV.local`. See
+    /// [`is_valid_mdns_name`](crate::validate::is_valid_mdns_name).
+    ///
+    /// Such hosts are stored as given, so the resulting `HostPort` only parses
+    /// back from its `Display` output with these options, not with `try_from`
+    /// or serde.
+    #[must_use]
+    pub fn allow_mdns_names(mut self, value: bool) -> Self {
+        self.allow_mdns_names = value;
+        self
+    }
+
+    /// Allow well-known service names in place of the port, e.g. `quake.se:https`.
+    #[cfg(feature = "services")]
+    #[must_use]
+    pub fn allow_service_names(mut self, value: bool) -> Self {
+        self.allow_service_names = value;
+        self
+    }
+
+    /// Restrict accepted ports to the given range, e.g. `1024..=65535` to reject
+    /// privileged ports. Ports outside the range result in `PortOutOfRange`.
+    #[must_use]
+    pub fn port_range(mut self, range: RangeInclusive<u16>) -> Self {
+        self.port_range = range;
+        self
+    }
+
+    /// Maximum length of the host, 253 characters by default. Longer hosts result
+    /// in `TooLong`.
+    #[must_use]
+    pub fn max_host_length(mut self, value: usize) -> Self {
+        self.host_rules.max_length = value;
+        self
+    }
+
+    /// Maximum number of host labels, 127 by default. Hosts with more labels
+    /// result in `TooManyLabels`.
+    #[must_use]
+    pub fn max_labels(mut self, value: usize) -> Self {
+        self.host_rules.max_labels = value;
+        self
+    }
+
+    /// Maximum length of the `host:port` rendering, for protocols with tighter
+    /// constraints. Longer values result in `TooLong`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, HostPortParseError, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().max_length(14);
+    /// assert!(HostPort::parse_with("quake.se:28000", &options).is_ok());
+    /// assert_eq!(
+    ///     HostPort::parse_with("qw.quake.se:28000", &options),
+    ///     Err(HostPortParseError::TooLong { limit: 14, actual: 17 })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn max_length(mut self, value: usize) -> Self {
+        self.max_length = value;
+        self
+    }
+
+    /// Run an additional check on hosts that pass validation, e.g. to enforce
+    /// naming conventions. Hosts for which `check` returns `false` result in
+    /// `InvalidHost`. Replaces any previously set check.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, HostPortParseError, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().extra_host_check(|host| host.ends_with(".corp.example"));
+    /// assert!(HostPort::parse_with("db.corp.example:5432", &options).is_ok());
+    /// assert_eq!(
+    ///     HostPort::parse_with("quake.se:28000", &options),
+    ///     Err(HostPortParseError::InvalidHost("quake.se".to_string()))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn extra_host_check(
+        mut self,
+        check: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.extra_host_check = Some(HostCheck(Arc::new(check)));
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_default() {
+        let options = ParseOptions::default();
+        assert_eq!(options.host_rules, HostRules::rfc1123());
+        assert!(!options.allow_trailing_dot);
+        assert!(!options.trim_whitespace);
+        assert!(!options.strip_noise);
+        assert!(!options.strip_scheme);
+        assert!(!options.reject_zero_port);
+        assert!(!options.decode_percent);
+        assert!(!options.allow_srv_names);
+        assert!(!options.allow_mdns_names);
+        assert_eq!(options.port_range, 0..=65535);
+        assert_eq!(options.max_length, usize::MAX);
+        assert_eq!(options.extra_host_check, None);
+    }
+
+    #[test]
+    fn test_lenient() {
+        let options = ParseOptions::lenient();
+        assert!(!options.host_rules.allow_underscore);
+        assert!(options.allow_trailing_dot);
+        assert!(options.trim_whitespace);
+        assert!(options.strip_noise);
+        assert!(!options.strip_scheme);
+        assert_eq!(options.port_range, 0..=65535);
+    }
+
+    #[test]
+    #[cfg(feature = "services")]
+    fn test_allow_service_names() {
+        assert!(!ParseOptions::default().allow_service_names);
+        assert!(
+            ParseOptions::default()
+                .allow_service_names(true)
+                .allow_service_names
+        );
+    }
+
+    #[test]
+    fn test_setters() {
+        let options = ParseOptions::default()
+            .allow_underscore(true)
+            .allow_numeric_labels(true)
+            .allow_numeric_tld(false)
+            .allow_trailing_dot(true)
+            .trim_whitespace(true)
+            .strip_noise(true)
+            .strip_scheme(true)
+            .reject_zero_port(true)
+            .decode_percent(true)
+            .allow_srv_names(true)
+            .allow_mdns_names(true)
+            .port_range(1024..=2048)
+            .max_host_length(64)
+            .max_labels(8)
+            .max_length(70);
+        assert!(options.host_rules.allow_underscore);
+        assert!(options.host_rules.allow_numeric_labels);
+        assert!(!options.host_rules.allow_numeric_tld);
+        assert!(options.allow_trailing_dot);
+        assert!(options.trim_whitespace);
+        assert!(options.strip_noise);
+        assert!(options.strip_scheme);
+        assert!(options.reject_zero_port);
+        assert!(options.decode_percent);
+        assert!(options.allow_srv_names);
+        assert!(options.allow_mdns_names);
+        assert_eq!(options.port_range, 1024..=2048);
+        assert_eq!(options.host_rules.max_length, 64);
+        assert_eq!(options.host_rules.max_labels, 8);
+        assert_eq!(options.max_length, 70);
+    }
+
+    #[test]
+    fn test_extra_host_check() {
+        let options = ParseOptions::default().extra_host_check(|host| host.len() < 5);
+        let check = options.extra_host_check.as_ref().unwrap();
+        assert!(check.check("qw"));
+        assert!(!check.check("quake.se"));
+        assert_eq!(format!("{check:?}"), "HostCheck");
+
+        // compared by identity
+        assert_eq!(options.clone(), options);
+        assert_ne!(
+            options,
+            ParseOptions::default().extra_host_check(|host| host.len() < 5)
+        );
+    }
+
+    #[test]
+    fn test_host_rules() {
+        let options = ParseOptions::default()
+            .allow_underscore(true)
+            .host_rules(HostRules::rfc952());
+        assert_eq!(options.host_rules, HostRules::rfc952());
+
+        let options = ParseOptions::default()
+            .host_rules(HostRules::rfc952())
+            .allow_underscore(true);
+        assert!(options.host_rules.allow_underscore);
+        assert!(!options.host_rules.allow_leading_digit);
+    }
+}
@@ -0,0 +1,184 @@
+//! Ordering wrappers keyed on a single component of `HostPort`.
+
+use crate::HostPort;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// A [`HostPort`] that compares, orders and hashes by host only, for sorting
+/// and `BTreeMap` keys without a custom comparator.
+///
+/// Hosts compare like [`Host`](crate::Host), i.e. ignoring ASCII case and with
+/// IP addresses first, so values with the same host and different ports are
+/// equal.
+///
+/// # Examples
+/// ```
+/// use hostport::{ByHost, HostPort};
+///
+/// let mut servers = vec![
+///     ByHost(HostPort::new("quake.se", 27500).unwrap()),
+///     ByHost(HostPort::new("qw.quake.se", 28000).unwrap()),
+///     ByHost(HostPort::new("10.10.10.10", 28501).unwrap()),
+/// ];
+/// servers.sort();
+/// assert_eq!(servers[0].0, "10.10.10.10:28501");
+/// assert_eq!(servers[1].0, "quake.se:27500");
+/// assert_eq!(servers[2].0, "qw.quake.se:28000");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ByHost(pub HostPort);
+
+/// A [`HostPort`] that compares, orders and hashes by port only, for sorting
+/// and `BTreeMap` keys without a custom comparator.
+///
+/// Values with the same port and different hosts are equal.
+///
+/// # Examples
+/// ```
+/// use hostport::{ByPort, HostPort};
+///
+/// let mut servers = vec![
+///     ByPort(HostPort::new("quake.se", 28000).unwrap()),
+///     ByPort(HostPort::new("10.10.10.10", 27500).unwrap()),
+/// ];
+/// servers.sort();
+/// assert_eq!(servers[0].0, "10.10.10.10:27500");
+/// assert_eq!(servers[1].0, "quake.se:28000");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ByPort(pub HostPort);
+
+impl PartialEq for ByHost {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.host == other.0.host
+    }
+}
+
+impl Eq for ByHost {}
+
+impl Ord for ByHost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.host.cmp(&other.0.host)
+    }
+}
+
+impl PartialOrd for ByHost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for ByHost {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.host.hash(state);
+    }
+}
+
+impl From<HostPort> for ByHost {
+    fn from(hostport: HostPort) -> Self {
+        ByHost(hostport)
+    }
+}
+
+impl PartialEq for ByPort {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.port == other.0.port
+    }
+}
+
+impl Eq for ByPort {}
+
+impl Ord for ByPort {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.port.cmp(&other.0.port)
+    }
+}
+
+impl PartialOrd for ByPort {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for ByPort {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.port.hash(state);
+    }
+}
+
+impl From<HostPort> for ByPort {
+    fn from(hostport: HostPort) -> Self {
+        ByPort(hostport)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use std::collections::{BTreeMap, HashSet};
+
+    #[test]
+    fn test_by_host() -> Result<()> {
+        assert_eq!(
+            ByHost(HostPort::new("quake.se", 27500)?),
+            ByHost(HostPort::new("QUAKE.se", 28000)?)
+        );
+        assert!(ByHost(HostPort::new("::1", 28000)?) < ByHost(HostPort::new("a.se", 1)?));
+
+        let mut ports: BTreeMap<ByHost, Vec<u16>> = BTreeMap::new();
+        for value in ["qw.quake.se:28000", "quake.se:27500", "qw.quake.se:28001"] {
+            let hostport = HostPort::try_from(value)?;
+            let port = hostport.port();
+            ports.entry(ByHost(hostport)).or_default().push(port);
+        }
+        let ports: Vec<_> = ports
+            .into_iter()
+            .map(|(key, ports)| (key.0.host().to_string(), ports))
+            .collect();
+        assert_eq!(
+            ports,
+            vec![
+                ("quake.se".to_string(), vec![27500]),
+                ("qw.quake.se".to_string(), vec![28000, 28001]),
+            ]
+        );
+
+        let hosts: HashSet<_> = [
+            ByHost(HostPort::new("quake.se", 27500)?),
+            ByHost(HostPort::new("Quake.se", 28000)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(hosts.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_by_port() -> Result<()> {
+        assert_eq!(
+            ByPort(HostPort::new("quake.se", 28000)?),
+            ByPort::from(HostPort::new("::1", 28000)?)
+        );
+
+        let mut servers = vec![
+            ByPort(HostPort::new("a.se", 28000)?),
+            ByPort(HostPort::new("b.se", 27500)?),
+            ByPort(HostPort::new("c.se", 28000)?),
+        ];
+        servers.sort();
+        let servers: Vec<_> = servers.into_iter().map(|value| value.0).collect();
+        assert_eq!(servers, vec!["b.se:27500", "a.se:28000", "c.se:28000"]);
+
+        let ports: HashSet<_> = [
+            ByPort(HostPort::new("a.se", 28000)?),
+            ByPort(HostPort::new("b.se", 28000)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(ports.len(), 1);
+        Ok(())
+    }
+}
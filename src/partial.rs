@@ -0,0 +1,283 @@
+//! Incremental parsing from network buffers.
+
+use crate::validate::{self, HostRules};
+use crate::{HostPort, HostPortParseError};
+
+/// Maximum length of a `host:port` token: 253 bytes of host, brackets, colon
+/// and a 5-digit port.
+const MAX_TOKEN_LEN: usize = 253 + 2 + 1 + 5;
+
+/// Result of incrementally parsing a `HostPort`, see [`HostPort::parse_partial`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Partial {
+    /// A complete `HostPort` followed by a delimiter was parsed. `consumed` is
+    /// the number of bytes read, including leading delimiters and the
+    /// terminating delimiter.
+    Complete {
+        /// Parsed `HostPort`.
+        hostport: HostPort,
+
+        /// Number of bytes consumed, including delimiters.
+        consumed: usize,
+    },
+
+    /// The buffer holds a valid prefix but no delimiter yet, more input is needed.
+    Incomplete,
+}
+
+impl HostPort {
+    /// Parses a `HostPort` token from the start of a buffer that may hold partial
+    /// input, e.g. data read from a socket. A token is terminated by ASCII
+    /// whitespace, `,`, `;` or a NUL byte, and leading delimiters are skipped.
+    ///
+    /// Returns [`Partial::Incomplete`] while the buffer is a plausible prefix of a
+    /// token, and an error as soon as it can not become valid: on an invalid
+    /// host character, a non-digit port or a port above `65535`. At end of
+    /// input, parse any remaining bytes with [`HostPort::from_bytes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, Partial};
+    ///
+    /// assert_eq!(HostPort::parse_partial(b"quake.se:28").unwrap(), Partial::Incomplete);
+    ///
+    /// let buf = b"quake.se:28000\r\n[::1]:27500\r\n";
+    /// let result = HostPort::parse_partial(buf).unwrap();
+    /// assert_eq!(
+    ///     result,
+    ///     Partial::Complete {
+    ///         hostport: HostPort::new("quake.se", 28000).unwrap(),
+    ///         consumed: 15,
+    ///     }
+    /// );
+    ///
+    /// // the leftover "\n" is skipped
+    /// let result = HostPort::parse_partial(&buf[15..]).unwrap();
+    /// assert_eq!(
+    ///     result,
+    ///     Partial::Complete {
+    ///         hostport: HostPort::new("::1", 27500).unwrap(),
+    ///         consumed: 13,
+    ///     }
+    /// );
+    /// ```
+    pub fn parse_partial(buf: &[u8]) -> Result<Partial, HostPortParseError> {
+        let start = buf
+            .iter()
+            .position(|&byte| !is_delimiter(byte))
+            .unwrap_or(buf.len());
+        let token = &buf[start..];
+        match token.iter().position(|&byte| is_delimiter(byte)) {
+            Some(index) => Ok(Partial::Complete {
+                hostport: HostPort::from_bytes(&token[..index])?,
+                consumed: start + index + 1,
+            }),
+            None => {
+                if token.len() > MAX_TOKEN_LEN {
+                    return Err(HostPortParseError::InputTooLong(MAX_TOKEN_LEN));
+                }
+                if !token.iter().all(|&byte| is_token_byte(byte)) {
+                    return Err(HostPortParseError::InvalidEncoding);
+                }
+                // token bytes are ASCII
+                check_prefix(std::str::from_utf8(token).unwrap_or_default())?;
+                Ok(Partial::Incomplete)
+            }
+        }
+    }
+}
+
+/// Returns an error if the token can not become a valid `host:port`, whatever
+/// follows.
+fn check_prefix(token: &str) -> Result<(), HostPortParseError> {
+    if let Some(rest) = token.strip_prefix('[') {
+        let Some((host, rest)) = rest.split_once(']') else {
+            let (addr, zone) = rest.split_once('%').unwrap_or((rest, ""));
+            let is_addr_char = |c: char| c.is_ascii_hexdigit() || matches!(c, ':' | '.');
+            let is_zone_char =
+                |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~');
+            if !addr.chars().all(is_addr_char) || !zone.chars().all(is_zone_char) {
+                return Err(HostPortParseError::InvalidHost(rest.to_string()));
+            }
+            return Ok(());
+        };
+        if !validate::is_valid_scoped_ipv6(host) {
+            return Err(HostPortParseError::InvalidHost(host.to_string()));
+        }
+        return match rest.strip_prefix(':') {
+            Some(port) => check_port_prefix(port),
+            None if rest.is_empty() => Ok(()),
+            None => Err(HostPortParseError::InvalidFormat),
+        };
+    }
+
+    let (host, port) = match token.split_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (token, None),
+    };
+    let rules = HostRules::default();
+    if let Some((position, character)) = host
+        .char_indices()
+        .find(|&(_, c)| !rules.is_valid_host_char(c))
+    {
+        return Err(HostPortParseError::InvalidHostChar {
+            character,
+            position,
+        });
+    }
+    match port {
+        // IPv6 hosts must be bracketed
+        Some(port) if port.contains(':') => {
+            let (host, _) = token.rsplit_once(':').unwrap_or_default();
+            Err(HostPortParseError::InvalidHost(host.to_string()))
+        }
+        Some(port) => check_port_prefix(port),
+        None => Ok(()),
+    }
+}
+
+fn check_port_prefix(port: &str) -> Result<(), HostPortParseError> {
+    if !port.chars().all(|c| c.is_ascii_digit()) {
+        return Err(HostPortParseError::InvalidPort(port.to_string()));
+    }
+    let value = port
+        .parse::<u64>()
+        .unwrap_or(if port.is_empty() { 0 } else { u64::MAX });
+    if value > u64::from(u16::MAX) {
+        return Err(HostPortParseError::PortOutOfRange {
+            value,
+            min: 0,
+            max: u16::MAX,
+        });
+    }
+    Ok(())
+}
+
+fn is_delimiter(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || matches!(byte, b',' | b';' | b'\0')
+}
+
+fn is_token_byte(byte: u8) -> bool {
+    byte.is_ascii_graphic()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_partial() -> Result<()> {
+        // incomplete
+        for buf in [
+            &b""[..],
+            b"\r\n",
+            b"quake",
+            b"quake.se:",
+            b"quake.se:28000",
+            b"\nquake.se:65535",
+            b"[::1",
+            b"[fe80::1%eth",
+            b"[::1]",
+            b"[::1]:",
+            b"[::1]:2",
+        ] {
+            assert_eq!(HostPort::parse_partial(buf)?, Partial::Incomplete);
+        }
+
+        // complete
+        for (buf, expected, consumed) in [
+            (&b"quake.se:28000 "[..], "quake.se:28000", 15),
+            (b"quake.se:28000\nquake", "quake.se:28000", 15),
+            (b"[::1]:28000,quake.se:1", "[::1]:28000", 12),
+            (b"10.10.10.10:28000;", "10.10.10.10:28000", 18),
+            (b"10.10.10.10:28000\0", "10.10.10.10:28000", 18),
+            (b"\nquake.se:28000\r\n", "quake.se:28000", 16),
+            (b" ,;quake.se:28000 ", "quake.se:28000", 18),
+        ] {
+            assert_eq!(
+                HostPort::parse_partial(buf)?,
+                Partial::Complete {
+                    hostport: HostPort::try_from(expected)?,
+                    consumed,
+                }
+            );
+        }
+
+        // invalid
+        assert_eq!(
+            HostPort::parse_partial(b"quake.se\n"),
+            Err(HostPortParseError::InvalidFormat)
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"quake|se:28000 "),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '|',
+                position: 5,
+            })
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"qu\xffake"),
+            Err(HostPortParseError::InvalidEncoding)
+        );
+
+        // invalid prefix
+        assert_eq!(
+            HostPort::parse_partial(b"quake|||"),
+            Err(HostPortParseError::InvalidHostChar {
+                character: '|',
+                position: 5,
+            })
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"quake.se:999999"),
+            Err(HostPortParseError::PortOutOfRange {
+                value: 999999,
+                min: 0,
+                max: 65535,
+            })
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"quake.se:28a"),
+            Err(HostPortParseError::InvalidPort("28a".to_string()))
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"::1:28000"),
+            Err(HostPortParseError::InvalidHost("::1".to_string()))
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"[::g"),
+            Err(HostPortParseError::InvalidHost("::g".to_string()))
+        );
+        assert_eq!(
+            HostPort::parse_partial(b"[::1]x"),
+            Err(HostPortParseError::InvalidFormat)
+        );
+        assert_eq!(
+            HostPort::parse_partial(&[b'a'; MAX_TOKEN_LEN + 1]),
+            Err(HostPortParseError::InputTooLong(MAX_TOKEN_LEN))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_partial_chunks() -> Result<()> {
+        let input = b"quake.se:28000 [::1]:27500\n";
+        let mut buf = Vec::new();
+        let mut parsed = Vec::new();
+
+        for chunk in input.chunks(4) {
+            buf.extend_from_slice(chunk);
+            while let Partial::Complete { hostport, consumed } = HostPort::parse_partial(&buf)? {
+                parsed.push(hostport);
+                buf.drain(..consumed);
+            }
+        }
+
+        assert_eq!(parsed, vec!["quake.se:28000", "[::1]:27500"]);
+        assert!(buf.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Port number.
+
+use crate::HostPortParseError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Represents a port number, classified per the IANA port ranges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Port(u16);
+
+impl Port {
+    /// Creates a new `Port` instance.
+    #[must_use]
+    pub const fn new(port: u16) -> Port {
+        Port(port)
+    }
+
+    /// Returns the port number.
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if the port is a well-known (system) port, `0-1023`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Port;
+    ///
+    /// assert!(Port::new(80).is_well_known());
+    /// assert!(!Port::new(28000).is_well_known());
+    /// ```
+    #[must_use]
+    pub const fn is_well_known(self) -> bool {
+        self.0 <= 1023
+    }
+
+    /// Returns `true` if the port is a registered (user) port, `1024-49151`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Port;
+    ///
+    /// assert!(Port::new(28000).is_registered());
+    /// assert!(!Port::new(80).is_registered());
+    /// ```
+    #[must_use]
+    pub const fn is_registered(self) -> bool {
+        self.0 >= 1024 && self.0 <= 49151
+    }
+
+    /// Returns `true` if the port is an ephemeral (dynamic or private) port, `49152-65535`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::Port;
+    ///
+    /// assert!(Port::new(50000).is_ephemeral());
+    /// assert!(!Port::new(28000).is_ephemeral());
+    /// ```
+    #[must_use]
+    pub const fn is_ephemeral(self) -> bool {
+        self.0 >= 49152
+    }
+}
+
+/// Parses a `Port` of ASCII digits, like the port of `host:port`, telling
+/// non-numeric values apart from out-of-range numbers.
+///
+/// # Examples
+/// ```
+/// use hostport::{HostPortParseError, Port};
+///
+/// assert_eq!("28000".parse::<Port>(), Ok(Port::new(28000)));
+/// assert_eq!(
+///     "70000".parse::<Port>(),
+///     Err(HostPortParseError::PortOutOfRange { value: 70000, min: 0, max: 65535 })
+/// );
+/// assert_eq!(
+///     "http".parse::<Port>(),
+///     Err(HostPortParseError::InvalidPort("http".to_string()))
+/// );
+/// ```
+impl FromStr for Port {
+    type Err = HostPortParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        crate::parse_port(value).map(Port)
+    }
+}
+
+impl TryFrom<&str> for Port {
+    type Error = HostPortParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<u16> for Port {
+    fn from(port: u16) -> Self {
+        Port(port)
+    }
+}
+
+impl From<Port> for u16 {
+    fn from(port: Port) -> Self {
+        port.0
+    }
+}
+
+impl Display for Port {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_classification() {
+        for (port, well_known, registered, ephemeral) in [
+            (0, true, false, false),
+            (1023, true, false, false),
+            (1024, false, true, false),
+            (49151, false, true, false),
+            (49152, false, false, true),
+            (65535, false, false, true),
+        ] {
+            let port = Port::new(port);
+            assert_eq!(port.is_well_known(), well_known);
+            assert_eq!(port.is_registered(), registered);
+            assert_eq!(port.is_ephemeral(), ephemeral);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0".parse::<Port>(), Ok(Port::new(0)));
+        assert_eq!("65535".parse::<Port>(), Ok(Port::new(65535)));
+        assert_eq!(
+            "65536".parse::<Port>(),
+            Err(HostPortParseError::PortOutOfRange {
+                value: 65536,
+                min: 0,
+                max: 65535
+            })
+        );
+        assert_eq!(
+            "99999999999999999999999".parse::<Port>(),
+            Err(HostPortParseError::PortOutOfRange {
+                value: u64::MAX,
+                min: 0,
+                max: 65535
+            })
+        );
+        for value in ["", "-1", "+1", "1a", " 1"] {
+            assert_eq!(
+                value.parse::<Port>(),
+                Err(HostPortParseError::InvalidPort(value.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Port::from(28000).get(), 28000);
+        assert_eq!(u16::from(Port::new(28000)), 28000);
+        assert_eq!(Port::new(28000).to_string(), "28000");
+        assert_eq!(Port::try_from("28000"), Ok(Port::new(28000)));
+    }
+}
@@ -0,0 +1,247 @@
+//! Integration with `miette` for pretty error reports.
+
+use crate::HostPortParseError;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+use std::error::Error;
+use std::fmt::Display;
+
+impl Diagnostic for HostPortParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(format!("hostport::{}", self.error_code())))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let help = match self {
+            HostPortParseError::InvalidFormat => {
+                "use host:port, e.g. quake.se:28000, with IPv6 hosts in brackets, e.g. [::1]:28000"
+            }
+            HostPortParseError::InvalidHost(_) | HostPortParseError::InvalidHostChar { .. } => {
+                "hosts are domains, network aliases or IP addresses, with labels of letters, digits and hyphens"
+            }
+            HostPortParseError::InvalidPort(_) => "ports are numbers in range 0-65535",
+            HostPortParseError::PortOutOfRange { min, max, .. } => {
+                return Some(Box::new(format!("use a port in range {min}-{max}")));
+            }
+            HostPortParseError::TrailingInput(_) => {
+                "remove the path, query or fragment after the port"
+            }
+            _ => return None,
+        };
+        Some(Box::new(help))
+    }
+}
+
+/// A [`HostPortParseError`] together with the input it occurred in, reported
+/// by `miette` with a labeled span over the offending part of the input.
+///
+/// # Examples
+/// ```
+/// use hostport::HostPort;
+///
+/// let input = "quake|se:28000";
+/// let report = HostPort::try_from(input).unwrap_err().with_input(input);
+/// assert_eq!(report.to_string(), "Invalid host: invalid character '|' at position 5");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseReport {
+    input: String,
+    error: HostPortParseError,
+}
+
+impl HostPortParseError {
+    /// Attaches the input that failed to parse, for reporting with `miette`.
+    #[must_use]
+    pub fn with_input(self, input: &str) -> ParseReport {
+        ParseReport {
+            input: input.to_string(),
+            error: self,
+        }
+    }
+}
+
+impl ParseReport {
+    /// Returns the input that failed to parse.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the error.
+    #[must_use]
+    pub fn error(&self) -> &HostPortParseError {
+        &self.error
+    }
+
+    /// Returns the byte offset and length of the offending part of the input,
+    /// and a label for it.
+    fn span(&self) -> ((usize, usize), &'static str) {
+        let input = self.input.as_str();
+        let whole = (0, input.len());
+        let find = |part: &str| {
+            input
+                .rfind(part)
+                .filter(|_| !part.is_empty())
+                .map_or(whole, |offset| (offset, part.len()))
+        };
+
+        match &self.error {
+            HostPortParseError::InvalidHost(host) | HostPortParseError::InvalidIpv4(host) => {
+                (find(host), "invalid host")
+            }
+            HostPortParseError::InvalidHostChar {
+                character,
+                position,
+            } => {
+                let trimmed = input.trim_start();
+                let host_offset =
+                    input.len() - trimmed.len() + usize::from(trimmed.starts_with('['));
+                let offset = host_offset + position;
+                let span = match input.get(offset..) {
+                    Some(rest) if rest.starts_with(*character) => (offset, character.len_utf8()),
+                    _ => find(&character.to_string()),
+                };
+                (span, "invalid character")
+            }
+            HostPortParseError::InvalidPort(message) => {
+                let port = message.split(", ").next().unwrap_or_default();
+                let span = match port {
+                    "" => (input.len(), 0),
+                    port => find(port),
+                };
+                (span, "invalid port")
+            }
+            HostPortParseError::PortOutOfRange { value, .. } => {
+                (find(&value.to_string()), "port out of range")
+            }
+            HostPortParseError::TrailingInput(suffix) => (find(suffix), "unexpected input"),
+            HostPortParseError::InvalidUserinfo(userinfo) => (find(userinfo), "invalid userinfo"),
+            HostPortParseError::UnknownScheme(scheme) => (find(scheme), "unknown scheme"),
+            HostPortParseError::InvalidPattern(pattern) => (find(pattern), "invalid pattern"),
+            _ => (whole, "here"),
+        }
+    }
+}
+
+impl Display for ParseReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Error for ParseReport {}
+
+impl Diagnostic for ParseReport {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.input)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let (span, label) = self.span();
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            Some(label.to_string()),
+            span,
+        ))))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::{HostPort, ParseOptions};
+    use pretty_assertions::assert_eq;
+
+    fn report(input: &str) -> ParseReport {
+        HostPort::try_from(input).unwrap_err().with_input(input)
+    }
+
+    fn labels(report: &ParseReport) -> Vec<(usize, usize, String)> {
+        report
+            .labels()
+            .unwrap()
+            .map(|label| {
+                (
+                    label.offset(),
+                    label.len(),
+                    label.label().unwrap_or_default().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let error = HostPortParseError::InvalidPort("abc".to_string());
+        assert_eq!(error.code().unwrap().to_string(), "hostport::invalid_port");
+        assert_eq!(
+            error.help().unwrap().to_string(),
+            "ports are numbers in range 0-65535"
+        );
+        assert_eq!(
+            HostPortParseError::PortOutOfRange {
+                value: 80,
+                min: 1024,
+                max: 65535
+            }
+            .help()
+            .unwrap()
+            .to_string(),
+            "use a port in range 1024-65535"
+        );
+        assert!(HostPortParseError::TooManyEntries(1).help().is_none());
+    }
+
+    #[test]
+    fn test_parse_report() {
+        for (input, expected) in [
+            ("quake|se:28000", (5, 1, "invalid character")),
+            ("[::1]:28000/path", (11, 5, "unexpected input")),
+            ("quake..se:28000", (0, 9, "invalid host")),
+            ("quake.se:abc", (9, 3, "invalid port")),
+            ("quake.se:", (9, 0, "invalid port")),
+            ("quake.se:99999", (9, 5, "port out of range")),
+            ("quake.se", (0, 8, "here")),
+        ] {
+            let report = report(input);
+            assert_eq!(report.input(), input);
+            assert_eq!(
+                labels(&report),
+                vec![(expected.0, expected.1, expected.2.to_string())],
+                "{input}"
+            );
+            assert_eq!(report.to_string(), report.error().to_string());
+            assert_eq!(
+                report.code().unwrap().to_string(),
+                report.error().code().unwrap().to_string()
+            );
+        }
+
+        let input = "  quake|se:28000";
+        let options = ParseOptions::default().trim_whitespace(true);
+        let report = HostPort::parse_with(input, &options)
+            .unwrap_err()
+            .with_input(input);
+        assert_eq!(
+            labels(&report),
+            vec![(7, 1, "invalid character".to_string())]
+        );
+
+        let input = "quake.se:80";
+        let options = ParseOptions::default().port_range(1024..=65535);
+        let report = HostPort::parse_with(input, &options)
+            .unwrap_err()
+            .with_input(input);
+        assert_eq!(
+            labels(&report),
+            vec![(9, 2, "port out of range".to_string())]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Well-known service ports.
+
+/// Returns the port of a well-known service or URL scheme (case-insensitive),
+/// e.g. `https` → `443`.
+///
+/// # Examples
+/// ```
+/// use hostport::service_port;
+///
+/// assert_eq!(service_port("https"), Some(443));
+/// assert_eq!(service_port("SSH"), Some(22));
+/// assert_eq!(service_port("foo"), None);
+/// ```
+#[cfg(feature = "services")]
+#[must_use]
+pub fn service_port(name: &str) -> Option<u16> {
+    well_known_port(name)
+}
+
+/// Looks up the port of a well-known service or URL scheme, also used for
+/// scheme default ports without the `services` feature.
+pub(crate) fn well_known_port(name: &str) -> Option<u16> {
+    let port = match name.to_ascii_lowercase().as_str() {
+        "ftp" => 21,
+        "ssh" | "sftp" => 22,
+        "telnet" => 23,
+        "smtp" => 25,
+        "dns" | "domain" => 53,
+        "gopher" => 70,
+        "http" | "ws" => 80,
+        "pop3" => 110,
+        "nntp" => 119,
+        "ntp" => 123,
+        "imap" => 143,
+        "snmp" => 161,
+        "ldap" => 389,
+        "https" | "wss" => 443,
+        "smtps" => 465,
+        "rtsp" => 554,
+        "ldaps" => 636,
+        "imaps" => 993,
+        "pop3s" => 995,
+        "mqtt" => 1883,
+        "mysql" => 3306,
+        "rdp" => 3389,
+        "sip" => 5060,
+        "postgres" | "postgresql" => 5432,
+        "amqp" => 5672,
+        "irc" => 6667,
+        "redis" => 6379,
+        "git" => 9418,
+        "mongodb" => 27017,
+        _ => return None,
+    };
+    Some(port)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_well_known_port() {
+        assert_eq!(well_known_port("http"), Some(80));
+        assert_eq!(well_known_port("HTTPS"), Some(443));
+        assert_eq!(well_known_port("wss"), Some(443));
+        assert_eq!(well_known_port("ssh"), Some(22));
+        assert_eq!(well_known_port("domain"), Some(53));
+        assert_eq!(well_known_port("postgresql"), Some(5432));
+        assert_eq!(well_known_port("tcp"), None);
+        assert_eq!(well_known_port(""), None);
+    }
+
+    #[test]
+    #[cfg(feature = "services")]
+    fn test_service_port() {
+        assert_eq!(service_port("https"), well_known_port("https"));
+        assert_eq!(service_port("foo"), None);
+    }
+}
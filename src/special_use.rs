@@ -0,0 +1,145 @@
+//! Special-use domain names.
+
+use crate::HostPort;
+
+/// Kind of special-use domain name (RFC 6761 and later registrations).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpecialUseKind {
+    /// `localhost` and `*.localhost`, resolving to loopback (RFC 6761).
+    Localhost,
+
+    /// `*.invalid`, guaranteed not to exist (RFC 6761).
+    Invalid,
+
+    /// `*.test`, for testing (RFC 6761).
+    Test,
+
+    /// `example`, `example.com`, `example.net` and `example.org`, for documentation (RFC 6761).
+    Example,
+
+    /// `*.local`, resolved with multicast DNS (RFC 6762).
+    Local,
+
+    /// `*.onion`, Tor onion services (RFC 7686).
+    Onion,
+
+    /// `*.home.arpa`, residential home networks (RFC 8375).
+    HomeArpa,
+
+    /// `*.alt`, non-DNS name resolution systems (RFC 9476).
+    Alt,
+}
+
+const SPECIAL_USE_DOMAINS: [(&str, SpecialUseKind); 11] = [
+    ("localhost", SpecialUseKind::Localhost),
+    ("invalid", SpecialUseKind::Invalid),
+    ("test", SpecialUseKind::Test),
+    ("example", SpecialUseKind::Example),
+    ("example.com", SpecialUseKind::Example),
+    ("example.net", SpecialUseKind::Example),
+    ("example.org", SpecialUseKind::Example),
+    ("local", SpecialUseKind::Local),
+    ("onion", SpecialUseKind::Onion),
+    ("home.arpa", SpecialUseKind::HomeArpa),
+    ("alt", SpecialUseKind::Alt),
+];
+
+impl HostPort {
+    /// Returns the kind of special-use domain name of the host, if any. Always
+    /// `None` for IP hosts.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::{HostPort, SpecialUseKind};
+    ///
+    /// let hostport = HostPort::new("printer.local", 631).unwrap();
+    /// assert_eq!(hostport.special_use_kind(), Some(SpecialUseKind::Local));
+    ///
+    /// let hostport = HostPort::new("www.example.com", 80).unwrap();
+    /// assert_eq!(hostport.special_use_kind(), Some(SpecialUseKind::Example));
+    ///
+    /// let hostport = HostPort::new("quake.se", 28000).unwrap();
+    /// assert_eq!(hostport.special_use_kind(), None);
+    /// ```
+    #[must_use]
+    pub fn special_use_kind(&self) -> Option<SpecialUseKind> {
+        if self.ip().is_some() {
+            return None;
+        }
+        SPECIAL_USE_DOMAINS
+            .iter()
+            .find(|(domain, _)| {
+                self.host().eq_ignore_ascii_case(domain) || self.is_subdomain_of(domain)
+            })
+            .map(|&(_, kind)| kind)
+    }
+
+    /// Returns `true` if the host is a multicast DNS name, i.e. ends in `.local`
+    /// (RFC 6762).
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// assert!(HostPort::new("printer.local", 631).unwrap().is_mdns());
+    /// assert!(!HostPort::new("printer.lan", 631).unwrap().is_mdns());
+    /// ```
+    #[must_use]
+    pub fn is_mdns(&self) -> bool {
+        self.special_use_kind() == Some(SpecialUseKind::Local)
+    }
+
+    /// Returns `true` if the host is a special-use domain name, e.g. `localhost`,
+    /// `*.test` or `*.local`. See [`HostPort::special_use_kind`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// assert!(HostPort::new("localhost", 28000).unwrap().is_special_use());
+    /// assert!(HostPort::new("server.test", 28000).unwrap().is_special_use());
+    /// assert!(!HostPort::new("quake.se", 28000).unwrap().is_special_use());
+    /// ```
+    #[must_use]
+    pub fn is_special_use(&self) -> bool {
+        self.special_use_kind().is_some()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_special_use_kind() -> Result<()> {
+        for (host, expected) in [
+            ("localhost", Some(SpecialUseKind::Localhost)),
+            ("app.LOCALHOST", Some(SpecialUseKind::Localhost)),
+            ("nothing.invalid", Some(SpecialUseKind::Invalid)),
+            ("server.test", Some(SpecialUseKind::Test)),
+            ("example", Some(SpecialUseKind::Example)),
+            ("example.com", Some(SpecialUseKind::Example)),
+            ("www.example.org", Some(SpecialUseKind::Example)),
+            ("printer.local", Some(SpecialUseKind::Local)),
+            ("abc.onion", Some(SpecialUseKind::Onion)),
+            ("router.home.arpa", Some(SpecialUseKind::HomeArpa)),
+            ("name.alt", Some(SpecialUseKind::Alt)),
+            ("quake.se", None),
+            ("notlocalhost", None),
+            ("example.se", None),
+            ("test.se", None),
+            ("arpa", None),
+            ("127.0.0.1", None),
+            ("::1", None),
+        ] {
+            let hostport = HostPort::new(host, 28000)?;
+            assert_eq!(hostport.special_use_kind(), expected, "{host}");
+            assert_eq!(hostport.is_special_use(), expected.is_some());
+            assert_eq!(hostport.is_mdns(), expected == Some(SpecialUseKind::Local));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+//! Hash of a `HostPort` that is stable across processes and releases.
+
+use crate::HostPort;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl HostPort {
+    /// Returns a hash that is stable across processes, platforms and releases,
+    /// for sharding or bucketing, unlike `Hash` with the randomly keyed hasher
+    /// of `std`.
+    ///
+    /// The hash is 64-bit FNV-1a over the UTF-8 bytes of the canonical
+    /// `host:port` rendering (see [`HostPort::canonicalize`]), e.g.
+    /// `[2001:db8::1]:28000`, so values that are equal after canonicalization
+    /// hash the same. It is not a cryptographic hash.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::HostPort;
+    ///
+    /// let hostport = HostPort::new("quake.se", 28000).unwrap();
+    /// assert_eq!(hostport.stable_hash(), 0x6d30_47c1_8f33_9900);
+    /// assert_eq!(
+    ///     HostPort::new("QUAKE.se", 28000).unwrap().stable_hash(),
+    ///     hostport.stable_hash()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn stable_hash(&self) -> u64 {
+        self.canonicalize()
+            .to_string()
+            .bytes()
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_stable_hash() -> Result<()> {
+        for (value, expected) in [
+            ("quake.se:28000", 0x6d30_47c1_8f33_9900),
+            ("Quake.SE:28000", 0x6d30_47c1_8f33_9900),
+            ("[2001:db8::1]:28000", 0x5056_bdae_11d1_b0fd),
+            ("[2001:0DB8:0:0::0001]:28000", 0x5056_bdae_11d1_b0fd),
+            ("10.10.10.10:28501", 0xe9c7_4bfb_12a2_2421),
+        ] {
+            assert_eq!(
+                HostPort::try_from(value)?.stable_hash(),
+                expected,
+                "{value}"
+            );
+        }
+        assert_eq!(HostPort::default().stable_hash(), 0xeb88_abf2_a6de_a6d0);
+        assert_ne!(
+            HostPort::new("quake.se", 28000)?.stable_hash(),
+            HostPort::new("quake.se", 28001)?.stable_hash()
+        );
+        Ok(())
+    }
+}
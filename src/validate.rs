@@ -1,6 +1,7 @@
 //! Utilities for validation.
 
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use thiserror::Error;
 
 /// Checks if the given string is a valid host (network alias, domain, or IP).
 ///
@@ -13,6 +14,8 @@ use std::net::Ipv4Addr;
 /// assert!(is_valid_host("quake-world.se"));
 /// assert!(is_valid_host("localhost"));
 /// assert!(is_valid_host("10.10.10.10"));
+/// assert!(is_valid_host("::1"));
+/// assert!(is_valid_host("fe80::1%eth0"));
 ///
 /// // invalid
 /// assert!(!is_valid_host("f%%"));
@@ -21,54 +24,662 @@ use std::net::Ipv4Addr;
 /// ```
 #[must_use]
 pub fn is_valid_host(value: &str) -> bool {
-    if value.is_empty() || value.len() > 255 {
-        return false;
+    HostRules::default().is_valid_host(value)
+}
+
+/// Checks if the given string is a valid host like [`is_valid_host`], but also
+/// allows underscores in labels, e.g. `my_service.internal` or `_sip._udp.quake.se`.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::{is_valid_host, is_valid_host_with_underscores};
+///
+/// assert!(is_valid_host_with_underscores("my_service.internal"));
+/// assert!(!is_valid_host("my_service.internal"));
+/// ```
+#[must_use]
+pub fn is_valid_host_with_underscores(value: &str) -> bool {
+    HostRules {
+        allow_underscore: true,
+        ..HostRules::default()
     }
+    .is_valid_host(value)
+}
 
-    if !value.chars().next().unwrap().is_ascii_alphanumeric() {
+/// Checks if the given string is a valid Tor v3 onion address: 56 base32
+/// characters encoding version 3, followed by `.onion`, optionally with
+/// subdomains. The checksum is not verified.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_onion;
+///
+/// assert!(is_valid_onion("2gzyxa5ihm7nsggfxnu52rck2vv4rvmdlkiu3zzui5du4xyclen53wid.onion"));
+/// assert!(is_valid_onion("www.2gzyxa5ihm7nsggfxnu52rck2vv4rvmdlkiu3zzui5du4xyclen53wid.onion"));
+/// assert!(!is_valid_onion("quake.onion"));
+/// ```
+#[cfg(feature = "anonymity")]
+#[must_use]
+pub fn is_valid_onion(value: &str) -> bool {
+    let Some(name) = value.strip_suffix(".onion") else {
         return false;
-    }
+    };
+    let (subdomains, address) = match name.rsplit_once('.') {
+        Some((subdomains, address)) => (Some(subdomains), address),
+        None => (None, name),
+    };
+    let is_v3 = address.len() == 56
+        && address.ends_with('d')
+        && base32_value(address.as_bytes()[54]).is_some_and(|value| value & 0b111 == 0);
+
+    is_v3
+        && address.bytes().all(|b| base32_value(b).is_some())
+        && subdomains.is_none_or(is_valid_host)
+}
 
-    if !value.chars().last().unwrap().is_ascii_alphanumeric() {
+/// Checks if the given string is a valid I2P hostname: a `.b32.i2p` address of
+/// 52 base32 characters (or at least 56 for encrypted leasesets), or a
+/// human-readable name ending in `.i2p`.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_i2p;
+///
+/// assert!(is_valid_i2p("ukeu3k5oycgaauneqgtnvselmt4yemvoilkln7jpvamvfx7dnkdq.b32.i2p"));
+/// assert!(is_valid_i2p("stats.i2p"));
+/// assert!(!is_valid_i2p("ukeu3k5o.b32.i2p"));
+/// assert!(!is_valid_i2p("quake.se"));
+/// ```
+#[cfg(feature = "anonymity")]
+#[must_use]
+pub fn is_valid_i2p(value: &str) -> bool {
+    let Some(name) = value.strip_suffix(".i2p") else {
         return false;
+    };
+    match name.strip_suffix(".b32") {
+        Some(address) => {
+            let is_b32 = address.len() == 52
+                && base32_value(address.as_bytes()[51]).is_some_and(|value| value & 0b1111 == 0);
+            (is_b32 || address.len() >= 56) && address.bytes().all(|b| base32_value(b).is_some())
+        }
+        None => is_valid_host(name) && !is_valid_ip(name),
     }
+}
 
-    if value.chars().any(|c| !is_valid_host_char(c)) {
-        return false;
+/// Returns the value of a lowercase RFC 4648 base32 character.
+#[cfg(feature = "anonymity")]
+fn base32_value(b: u8) -> Option<u8> {
+    match b {
+        b'a'..=b'z' => Some(b - b'a'),
+        b'2'..=b'7' => Some(b - b'2' + 26),
+        _ => None,
     }
+}
 
-    let parts: Vec<&str> = value.split('.').collect();
+/// Checks if the given string is a valid multicast DNS name ending in `.local`
+/// (RFC 6762). Labels are UTF-8 of at most 63 bytes and may contain any
+/// non-control characters, e.g. `Living Room TV.local`.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_mdns_name;
+///
+/// assert!(is_valid_mdns_name("printer.local"));
+/// assert!(is_valid_mdns_name("Living Room TV.local"));
+/// assert!(is_valid_mdns_name("Vardagsrummet-Högtalare.local"));
+/// assert!(!is_valid_mdns_name("printer.lan"));
+/// assert!(!is_valid_mdns_name("printer..local"));
+/// ```
+#[must_use]
+pub fn is_valid_mdns_name(value: &str) -> bool {
+    let is_local = value
+        .len()
+        .checked_sub(".local".len())
+        .and_then(|index| value.get(index..))
+        .is_some_and(|suffix| suffix.eq_ignore_ascii_case(".local"));
 
-    match parts.len() {
-        4 if value.chars().all(|c| c.is_ascii_digit() || c == '.') => {
-            value.parse::<Ipv4Addr>().is_ok()
+    is_local
+        && value.len() <= 255
+        && value
+            .split('.')
+            .all(|label| !label.is_empty() && label.len() <= 63)
+        && !value.chars().any(char::is_control)
+}
+
+/// Checks if the given string is a valid SRV record name, `_service._proto.name`
+/// (RFC 2782), where the service and protocol labels are RFC 6335 service names
+/// (at most 15 letters, digits and hyphens) prefixed with an underscore.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_srv_name;
+///
+/// assert!(is_valid_srv_name("_quake._udp.quake.se"));
+/// assert!(is_valid_srv_name("_sip._tcp.localhost"));
+/// assert!(!is_valid_srv_name("quake._udp.quake.se"));
+/// assert!(!is_valid_srv_name("_quake._udp"));
+/// ```
+#[must_use]
+pub fn is_valid_srv_name(value: &str) -> bool {
+    let mut parts = value.splitn(3, '.');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(service), Some(proto), Some(name)) => {
+            is_valid_service_label(service)
+                && is_valid_service_label(proto)
+                && is_valid_host(name)
+                && !is_valid_ip(name)
         }
-        _ => parts.into_iter().all(is_valid_label),
+        _ => false,
     }
 }
 
-fn is_valid_label(label: &str) -> bool {
-    if label.is_empty() || label.len() > 63 {
+fn is_valid_service_label(label: &str) -> bool {
+    label.strip_prefix('_').is_some_and(|name| {
+        name.len() <= 15 && HostRules::default().validate_label(name, 0).is_ok()
+    })
+}
+
+/// Checks if the given string is a valid Kubernetes service DNS name, validated
+/// with [`HostRules::kubernetes`]. Accepts the short forms `service` and
+/// `service.namespace`, and the forms `service.namespace.svc[.cluster-domain]`
+/// and `pod.service.namespace.svc[.cluster-domain]` of headless services.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_kubernetes_service;
+///
+/// assert!(is_valid_kubernetes_service("my-svc"));
+/// assert!(is_valid_kubernetes_service("my-svc.my-namespace"));
+/// assert!(is_valid_kubernetes_service("my-svc.my-namespace.svc.cluster.local"));
+/// assert!(is_valid_kubernetes_service("web-0.my-svc.my-namespace.svc.cluster.local"));
+/// assert!(!is_valid_kubernetes_service("a.b.my-svc.my-namespace.svc.cluster.local"));
+/// assert!(!is_valid_kubernetes_service("my_svc.my-namespace"));
+/// ```
+#[must_use]
+pub fn is_valid_kubernetes_service(value: &str) -> bool {
+    if !HostRules::kubernetes().is_valid_host(value) || is_valid_ip(value) {
         return false;
     }
-    if label.starts_with('-') || label.ends_with('-') {
-        return false;
+    let labels: Vec<&str> = value.split('.').collect();
+    match labels.iter().position(|&label| label == "svc") {
+        Some(index) => index == 2 || index == 3,
+        None => labels.len() <= 2,
     }
-    if label.chars().any(|c| !is_valid_label_char(c)) {
-        return false;
+}
+
+/// Validates the given host (network alias, domain, or IP), returning the first
+/// problem found. See [`is_valid_host`] for a boolean check.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::{validate_host, HostValidationError};
+///
+/// assert_eq!(validate_host("quake.se"), Ok(()));
+/// assert_eq!(validate_host(""), Err(HostValidationError::Empty));
+/// assert_eq!(
+///     validate_host("quake|se"),
+///     Err(HostValidationError::InvalidChar { character: '|', position: 5 })
+/// );
+/// ```
+pub fn validate_host(value: &str) -> Result<(), HostValidationError> {
+    HostRules::default().validate_host(value)
+}
+
+/// Errors that can occur while validating a host. Positions are byte offsets
+/// within the host.
+///
+/// # Variants
+/// - `Empty`: The host is empty.
+/// - `TooLong`: The host is longer than allowed, 253 characters by default.
+/// - `InvalidChar`: The host contains an invalid character.
+/// - `EmptyLabel`: The host contains an empty label, e.g. `quake..se`.
+/// - `LabelTooLong`: A label is longer than 63 characters.
+/// - `BadLabelEdge`: A label starts or ends with an invalid character, e.g. `-`.
+/// - `NumericLabel`: A label is all-numeric.
+/// - `LeadingDigit`: A label starts with a digit.
+/// - `NumericTld`: The rightmost label is all-numeric, e.g. `quake.123`.
+/// - `TooManyLabels`: The host has more labels than allowed.
+/// - `InvalidIpv4`: The host looks like an IPv4 address but is malformed.
+/// - `InvalidIpv6`: The host looks like an IPv6 address but is malformed.
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum HostValidationError {
+    /// The host is empty.
+    #[error("Host is empty")]
+    Empty,
+
+    /// The host is longer than allowed, 253 characters by default.
+    #[error("Host is too long: {actual} characters, expected at most {limit}")]
+    TooLong {
+        /// Maximum length.
+        limit: usize,
+
+        /// Actual length.
+        actual: usize,
+    },
+
+    /// The host contains an invalid character.
+    #[error("Invalid character '{character}' at position {position}")]
+    InvalidChar {
+        /// The offending character.
+        character: char,
+
+        /// Byte offset of the character.
+        position: usize,
+    },
+
+    /// The host contains an empty label.
+    #[error("Empty label at position {position}")]
+    EmptyLabel {
+        /// Byte offset of the label.
+        position: usize,
+    },
+
+    /// A label is longer than 63 characters.
+    #[error("Label too long at position {position}, expected at most 63 characters")]
+    LabelTooLong {
+        /// Byte offset of the label.
+        position: usize,
+    },
+
+    /// A label starts or ends with an invalid character.
+    #[error("Label starts or ends with an invalid character at position {position}")]
+    BadLabelEdge {
+        /// Byte offset of the offending character.
+        position: usize,
+    },
+
+    /// A label is all-numeric.
+    #[error("All-numeric label at position {position}")]
+    NumericLabel {
+        /// Byte offset of the label.
+        position: usize,
+    },
+
+    /// A label starts with a digit.
+    #[error("Label starts with a digit at position {position}")]
+    LeadingDigit {
+        /// Byte offset of the label.
+        position: usize,
+    },
+
+    /// The rightmost label is all-numeric.
+    #[error("All-numeric top-level domain at position {position}")]
+    NumericTld {
+        /// Byte offset of the label.
+        position: usize,
+    },
+
+    /// The host has more labels than allowed.
+    #[error("Too many labels: {actual}, expected at most {limit}")]
+    TooManyLabels {
+        /// Maximum number of labels.
+        limit: usize,
+
+        /// Actual number of labels.
+        actual: usize,
+    },
+
+    /// The host looks like an IPv4 address but is malformed.
+    #[error("Invalid IPv4 address")]
+    InvalidIpv4,
+
+    /// The host looks like an IPv6 address but is malformed.
+    #[error("Invalid IPv6 address")]
+    InvalidIpv6,
+}
+
+/// Checks if the given string is a valid IPv4 or IPv6 address (without brackets).
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_ip;
+///
+/// assert!(is_valid_ip("10.10.10.10"));
+/// assert!(is_valid_ip("::1"));
+/// assert!(!is_valid_ip("quake.se"));
+/// ```
+#[must_use]
+pub fn is_valid_ip(value: &str) -> bool {
+    is_valid_ipv4(value) || is_valid_ipv6(value)
+}
+
+/// Checks if the given string is a valid IPv4 address in dotted-decimal form.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_ipv4;
+///
+/// assert!(is_valid_ipv4("10.10.10.10"));
+/// assert!(!is_valid_ipv4("010.10.10.10"));
+/// assert!(!is_valid_ipv4("10.10.10"));
+/// ```
+#[must_use]
+pub fn is_valid_ipv4(value: &str) -> bool {
+    value.parse::<Ipv4Addr>().is_ok()
+}
+
+/// Checks if the given string could be interpreted as an IPv4 address by
+/// lenient parsers such as `inet_aton`, i.e. one to four dot-separated parts
+/// that are each decimal, octal or hexadecimal (`0x`) numbers.
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_ipv4_like;
+///
+/// assert!(is_ipv4_like("10.10.10.10"));
+/// assert!(is_ipv4_like("010.10.10.10"));
+/// assert!(is_ipv4_like("127.1"));
+/// assert!(is_ipv4_like("0x7f000001"));
+/// assert!(!is_ipv4_like("quake.se"));
+/// ```
+#[must_use]
+pub fn is_ipv4_like(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('.').collect();
+    (1..=4).contains(&parts.len()) && parts.into_iter().all(is_numeric_part)
+}
+
+fn is_numeric_part(part: &str) -> bool {
+    match part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+        Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()),
     }
-    if label.chars().all(|c| c.is_ascii_digit()) {
-        return false;
+}
+
+/// Checks if the given string is a valid IPv6 address (without brackets).
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_ipv6;
+///
+/// assert!(is_valid_ipv6("::1"));
+/// assert!(is_valid_ipv6("2001:db8::1"));
+/// assert!(!is_valid_ipv6("[::1]"));
+/// assert!(!is_valid_ipv6("10.10.10.10"));
+/// ```
+#[must_use]
+pub fn is_valid_ipv6(value: &str) -> bool {
+    value.parse::<Ipv6Addr>().is_ok()
+}
+
+/// Checks if the given string is a valid IPv6 address with an optional zone
+/// identifier (e.g. `fe80::1%eth0`).
+///
+/// # Examples
+/// ```
+/// use hostport::validate::is_valid_scoped_ipv6;
+///
+/// assert!(is_valid_scoped_ipv6("fe80::1%eth0"));
+/// assert!(is_valid_scoped_ipv6("fe80::1"));
+/// assert!(!is_valid_scoped_ipv6("fe80::1%"));
+/// ```
+#[must_use]
+pub fn is_valid_scoped_ipv6(value: &str) -> bool {
+    match value.split_once('%') {
+        Some((addr, zone)) => is_valid_ipv6(addr) && is_valid_zone(zone),
+        None => is_valid_ipv6(value),
     }
-    true
 }
 
-fn is_valid_host_char(c: char) -> bool {
-    is_valid_label_char(c) || c == '.'
+fn is_valid_zone(zone: &str) -> bool {
+    !zone.is_empty()
+        && zone
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'))
+}
+
+/// Rules applied when validating hosts, selectable at parse time with
+/// [`ParseOptions::host_rules`](crate::ParseOptions::host_rules).
+///
+/// # Examples
+/// ```
+/// use hostport::validate::HostRules;
+///
+/// assert!(HostRules::rfc1123().is_valid_host("1quake.se"));
+/// assert!(!HostRules::rfc952().is_valid_host("1quake.se"));
+/// assert!(HostRules::lenient().is_valid_host("my_service.123.internal"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HostRules {
+    /// Allow underscores in labels, e.g. `my_service.internal`.
+    pub allow_underscore: bool,
+
+    /// Allow all-numeric labels, e.g. `123.quake.se`.
+    pub allow_numeric_labels: bool,
+
+    /// Allow an all-numeric rightmost label (top-level domain), e.g. `quake.123`
+    /// or the single label `123`, when all-numeric labels are allowed. ICANN
+    /// forbids all-numeric TLDs.
+    pub allow_numeric_tld: bool,
+
+    /// Allow labels starting with a digit, e.g. `1quake.se`.
+    pub allow_leading_digit: bool,
+
+    /// Allow uppercase letters, e.g. `Quake.se`.
+    pub allow_uppercase: bool,
+
+    /// Maximum number of labels, 127 by default, e.g. `1` for single-label network aliases.
+    pub max_labels: usize,
+
+    /// Maximum length of the host, in characters, 253 by default.
+    pub max_length: usize,
+}
+
+impl Default for HostRules {
+    fn default() -> Self {
+        Self::rfc1123()
+    }
 }
 
-fn is_valid_label_char(c: char) -> bool {
-    c.is_ascii_alphanumeric() || c == '-'
+impl HostRules {
+    /// Rules of RFC 952: labels start with a letter and contain letters,
+    /// digits and hyphens.
+    #[must_use]
+    pub fn rfc952() -> Self {
+        Self {
+            allow_leading_digit: false,
+            ..Self::rfc1123()
+        }
+    }
+
+    /// Rules of RFC 1123 (default): like RFC 952, but labels may start with a digit.
+    /// All-numeric labels are rejected to avoid confusion with IPv4 addresses.
+    #[must_use]
+    pub fn rfc1123() -> Self {
+        Self {
+            allow_underscore: false,
+            allow_numeric_labels: false,
+            allow_numeric_tld: true,
+            allow_leading_digit: true,
+            allow_uppercase: true,
+            max_labels: 127,
+            max_length: 253,
+        }
+    }
+
+    /// Lenient rules for config files and server lists: like RFC 1123, but also
+    /// allows underscores and all-numeric labels other than the top-level
+    /// domain, so IPv4-like hosts such as `127.1` or `2130706433` are rejected.
+    #[must_use]
+    pub fn lenient() -> Self {
+        Self {
+            allow_underscore: true,
+            allow_numeric_labels: true,
+            allow_numeric_tld: false,
+            ..Self::rfc1123()
+        }
+    }
+
+    /// Rules for Kubernetes service and pod DNS names: RFC 1035 labels, which
+    /// start with a letter and contain only lowercase letters, digits and hyphens.
+    /// See [`is_valid_kubernetes_service`] for checking the name structure.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::validate::HostRules;
+    ///
+    /// let rules = HostRules::kubernetes();
+    /// assert!(rules.is_valid_host("my-svc.my-namespace.svc.cluster.local"));
+    /// assert!(!rules.is_valid_host("My-Svc.my-namespace.svc.cluster.local"));
+    /// assert!(!rules.is_valid_host("1svc.my-namespace.svc.cluster.local"));
+    /// ```
+    #[must_use]
+    pub fn kubernetes() -> Self {
+        Self {
+            allow_uppercase: false,
+            ..Self::rfc952()
+        }
+    }
+
+    /// Rules for Docker and Compose network aliases, e.g. `my_db`: a single label
+    /// of at most 63 characters, with underscores allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::validate::HostRules;
+    ///
+    /// let rules = HostRules::docker();
+    /// assert!(rules.is_valid_host("my_db"));
+    /// assert!(rules.is_valid_host("redis-1"));
+    /// assert!(!rules.is_valid_host("db.internal"));
+    /// ```
+    #[must_use]
+    pub fn docker() -> Self {
+        Self {
+            allow_underscore: true,
+            max_labels: 1,
+            ..Self::rfc1123()
+        }
+    }
+
+    /// Checks if the given string is a valid host (network alias, domain, or IP)
+    /// according to these rules.
+    #[must_use]
+    pub fn is_valid_host(&self, value: &str) -> bool {
+        self.validate_host(value).is_ok()
+    }
+
+    /// Validates the given host (network alias, domain, or IP) according to these
+    /// rules, returning the first problem found.
+    ///
+    /// # Examples
+    /// ```
+    /// use hostport::validate::{HostRules, HostValidationError};
+    ///
+    /// let rules = HostRules::default();
+    /// assert_eq!(rules.validate_host("quake.se"), Ok(()));
+    /// assert_eq!(
+    ///     rules.validate_host("quake.-se"),
+    ///     Err(HostValidationError::BadLabelEdge { position: 6 })
+    /// );
+    /// ```
+    pub fn validate_host(&self, value: &str) -> Result<(), HostValidationError> {
+        if value.is_empty() {
+            return Err(HostValidationError::Empty);
+        }
+        if value.len() > self.max_length {
+            return Err(HostValidationError::TooLong {
+                limit: self.max_length,
+                actual: value.len(),
+            });
+        }
+
+        if value.contains(':') {
+            if !is_valid_scoped_ipv6(value) {
+                return Err(HostValidationError::InvalidIpv6);
+            }
+            return Ok(());
+        }
+
+        if let Some((position, character)) = value
+            .char_indices()
+            .find(|&(_, c)| !self.is_valid_host_char(c))
+        {
+            return Err(HostValidationError::InvalidChar {
+                character,
+                position,
+            });
+        }
+
+        let parts: Vec<&str> = value.split('.').collect();
+
+        if parts.len() == 4 && value.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            if !is_valid_ipv4(value) {
+                return Err(HostValidationError::InvalidIpv4);
+            }
+            return Ok(());
+        }
+
+        if parts.len() > self.max_labels {
+            return Err(HostValidationError::TooManyLabels {
+                limit: self.max_labels,
+                actual: parts.len(),
+            });
+        }
+
+        let mut position = 0;
+        for label in parts {
+            self.validate_label(label, position)?;
+            position += label.len() + 1;
+        }
+
+        if !self.allow_numeric_tld {
+            let (position, tld) = value
+                .rsplit_once('.')
+                .map_or((0, value), |(head, tld)| (head.len() + 1, tld));
+            if tld.chars().all(|c| c.is_ascii_digit()) {
+                return Err(HostValidationError::NumericTld { position });
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a label starting at byte offset `position` within the host.
+    fn validate_label(&self, label: &str, position: usize) -> Result<(), HostValidationError> {
+        if label.is_empty() {
+            return Err(HostValidationError::EmptyLabel { position });
+        }
+        if label.len() > 63 {
+            return Err(HostValidationError::LabelTooLong { position });
+        }
+        if let Some((index, character)) = label
+            .char_indices()
+            .find(|&(_, c)| !self.is_valid_label_char(c))
+        {
+            return Err(HostValidationError::InvalidChar {
+                character,
+                position: position + index,
+            });
+        }
+        if !label.starts_with(|c| self.is_valid_label_edge(c)) {
+            return Err(HostValidationError::BadLabelEdge { position });
+        }
+        if !label.ends_with(|c| self.is_valid_label_edge(c)) {
+            return Err(HostValidationError::BadLabelEdge {
+                position: position + label.len() - 1,
+            });
+        }
+        if !self.allow_numeric_labels && label.chars().all(|c| c.is_ascii_digit()) {
+            return Err(HostValidationError::NumericLabel { position });
+        }
+        if !self.allow_leading_digit && label.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(HostValidationError::LeadingDigit { position });
+        }
+        Ok(())
+    }
+
+    fn is_valid_label_edge(&self, c: char) -> bool {
+        (c.is_ascii_alphanumeric() && (self.allow_uppercase || !c.is_ascii_uppercase()))
+            || (self.allow_underscore && c == '_')
+    }
+
+    pub(crate) fn is_valid_host_char(&self, c: char) -> bool {
+        self.is_valid_label_char(c) || c == '.'
+    }
+
+    fn is_valid_label_char(&self, c: char) -> bool {
+        self.is_valid_label_edge(c) || c == '-'
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +687,10 @@ fn is_valid_label_char(c: char) -> bool {
 mod tests {
     use super::*;
 
+    fn is_valid_label(label: &str) -> bool {
+        HostRules::default().validate_label(label, 0).is_ok()
+    }
+
     #[test]
     fn test_is_valid_label() {
         // invalid
@@ -112,6 +727,9 @@ mod tests {
         assert!(!is_valid_host("a.0"));
         assert!(!is_valid_host("quake|se"));
         assert!(!is_valid_host("1000.0.0.0"));
+        assert!(!is_valid_host("[::1]"));
+        assert!(!is_valid_host(":::1"));
+        assert!(!is_valid_host("quake.se:"));
 
         // valid
         assert!(is_valid_host("quake1.se"));
@@ -120,5 +738,462 @@ mod tests {
         assert!(is_valid_host("quake-world.se"));
         assert!(is_valid_host("localhost"));
         assert!(is_valid_host("10.10.10.10"));
+        assert!(is_valid_host("::1"));
+        assert!(is_valid_host("2001:db8::1"));
+        assert!(is_valid_host("::ffff:10.10.10.10"));
+    }
+
+    #[test]
+    fn test_validate_host() {
+        use HostValidationError::*;
+
+        assert_eq!(validate_host("quake.se"), Ok(()));
+        assert_eq!(validate_host("10.10.10.10"), Ok(()));
+        assert_eq!(validate_host("fe80::1%eth0"), Ok(()));
+
+        assert_eq!(validate_host(""), Err(Empty));
+        assert_eq!(
+            validate_host(&"a".repeat(254)),
+            Err(TooLong {
+                limit: 253,
+                actual: 254
+            })
+        );
+        assert_eq!(validate_host(&format!("{}a", "a.".repeat(126))), Ok(()));
+        assert_eq!(
+            validate_host(&format!("{}a", "a.".repeat(127))),
+            Err(TooLong {
+                limit: 253,
+                actual: 255
+            })
+        );
+        assert_eq!(
+            validate_host("quake se"),
+            Err(InvalidChar {
+                character: ' ',
+                position: 5
+            })
+        );
+        assert_eq!(validate_host("quake..se"), Err(EmptyLabel { position: 6 }));
+        assert_eq!(validate_host("quake.se."), Err(EmptyLabel { position: 9 }));
+        assert_eq!(
+            validate_host(&format!("quake.{}", "a".repeat(64))),
+            Err(LabelTooLong { position: 6 })
+        );
+        assert_eq!(
+            validate_host("-quake.se"),
+            Err(BadLabelEdge { position: 0 })
+        );
+        assert_eq!(
+            validate_host("quake-.se"),
+            Err(BadLabelEdge { position: 5 })
+        );
+        assert_eq!(
+            validate_host("quake.123"),
+            Err(NumericLabel { position: 6 })
+        );
+        assert_eq!(
+            HostRules::rfc952().validate_host("quake.1se"),
+            Err(LeadingDigit { position: 6 })
+        );
+        assert_eq!(validate_host("1000.0.0.0"), Err(InvalidIpv4));
+        assert_eq!(validate_host(":::1"), Err(InvalidIpv6));
+        assert_eq!(
+            validate_host("quake|se").unwrap_err().to_string(),
+            "Invalid character '|' at position 5"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anonymity")]
+    fn test_is_valid_onion() {
+        let address = "2gzyxa5ihm7nsggfxnu52rck2vv4rvmdlkiu3zzui5du4xyclen53wid";
+
+        // valid
+        assert!(is_valid_onion(&format!("{address}.onion")));
+        assert!(is_valid_onion(&format!("a.b.{address}.onion")));
+
+        // invalid
+        assert!(!is_valid_onion(address));
+        assert!(!is_valid_onion(".onion"));
+        assert!(!is_valid_onion(&format!("{}.onion", &address[1..])));
+        assert!(!is_valid_onion(&format!("{}x.onion", address)));
+        assert!(!is_valid_onion(&format!("{}e.onion", &address[..55])));
+        assert!(!is_valid_onion(&format!("{}1d.onion", &address[..54])));
+        assert!(!is_valid_onion(&format!(
+            "{}.onion",
+            address.to_uppercase()
+        )));
+        assert!(!is_valid_onion(&format!("-a.{address}.onion")));
+        assert!(!is_valid_onion("expyuzz4wqqyqhjn.onion"));
+    }
+
+    #[test]
+    #[cfg(feature = "anonymity")]
+    fn test_is_valid_i2p() {
+        let address = "ukeu3k5oycgaauneqgtnvselmt4yemvoilkln7jpvamvfx7dnkdq";
+
+        // valid
+        assert!(is_valid_i2p(&format!("{address}.b32.i2p")));
+        assert!(is_valid_i2p(&format!("{}.b32.i2p", "a".repeat(60))));
+        assert!(is_valid_i2p("stats.i2p"));
+        assert!(is_valid_i2p("forum.stats.i2p"));
+
+        // invalid
+        assert!(!is_valid_i2p(&format!("{}r.b32.i2p", &address[..51])));
+        assert!(!is_valid_i2p(&format!("{}1.b32.i2p", &address[..51])));
+        assert!(!is_valid_i2p(&format!("{}.b32.i2p", &address[..50])));
+        assert!(!is_valid_i2p(".b32.i2p"));
+        assert!(!is_valid_i2p("i2p"));
+        assert!(!is_valid_i2p("-stats.i2p"));
+        assert!(!is_valid_i2p("10.10.10.10.i2p"));
+    }
+
+    #[test]
+    fn test_is_valid_mdns_name() {
+        // valid
+        assert!(is_valid_mdns_name("printer.local"));
+        assert!(is_valid_mdns_name("PRINTER.LOCAL"));
+        assert!(is_valid_mdns_name("Living Room TV.local"));
+        assert!(is_valid_mdns_name("Högtalare_1.local"));
+        assert!(is_valid_mdns_name("a.b.local"));
+
+        // invalid
+        assert!(!is_valid_mdns_name("local"));
+        assert!(!is_valid_mdns_name(".local"));
+        assert!(!is_valid_mdns_name("printer.local."));
+        assert!(!is_valid_mdns_name("printer\t.local"));
+        assert!(!is_valid_mdns_name("printer\u{0}.local"));
+        assert!(!is_valid_mdns_name(&format!("{}.local", "ö".repeat(32))));
+        assert!(!is_valid_mdns_name("ölocal"));
+        assert!(!is_valid_mdns_name("quake.se"));
+    }
+
+    #[test]
+    fn test_is_valid_srv_name() {
+        // valid
+        assert!(is_valid_srv_name("_quake._udp.quake.se"));
+        assert!(is_valid_srv_name("_xmpp-server._tcp.quake.se"));
+        assert!(is_valid_srv_name("_sip._tls.localhost"));
+
+        // invalid
+        assert!(!is_valid_srv_name("_quake._udp"));
+        assert!(!is_valid_srv_name("_quake.udp.quake.se"));
+        assert!(!is_valid_srv_name("__quake._udp.quake.se"));
+        assert!(!is_valid_srv_name("_._udp.quake.se"));
+        assert!(!is_valid_srv_name("_123._udp.quake.se"));
+        assert!(!is_valid_srv_name("_-quake._udp.quake.se"));
+        assert!(!is_valid_srv_name("_a-very-long-service._udp.quake.se"));
+        assert!(!is_valid_srv_name("_quake._udp.10.10.10.10"));
+        assert!(!is_valid_srv_name("_quake._udp._quake.se"));
+        assert!(!is_valid_srv_name("quake.se"));
+    }
+
+    #[test]
+    fn test_is_valid_kubernetes_service() {
+        // valid
+        assert!(is_valid_kubernetes_service("my-svc"));
+        assert!(is_valid_kubernetes_service("my-svc.default"));
+        assert!(is_valid_kubernetes_service("my-svc.default.svc"));
+        assert!(is_valid_kubernetes_service(
+            "my-svc.default.svc.cluster.local"
+        ));
+        assert!(is_valid_kubernetes_service(
+            "web-0.my-svc.default.svc.cluster.local"
+        ));
+
+        // invalid
+        assert!(!is_valid_kubernetes_service("svc.cluster.local"));
+        assert!(!is_valid_kubernetes_service("my-svc.default.cluster.local"));
+        assert!(!is_valid_kubernetes_service(
+            "a.b.my-svc.default.svc.cluster.local"
+        ));
+        assert!(!is_valid_kubernetes_service("My-Svc.default"));
+        assert!(!is_valid_kubernetes_service("0-web.my-svc.default.svc"));
+        assert!(!is_valid_kubernetes_service("10.10.10.10"));
+        assert!(!is_valid_kubernetes_service("::1"));
+    }
+
+    #[test]
+    fn test_host_rules_presets() {
+        assert_eq!(HostRules::default(), HostRules::rfc1123());
+
+        // rfc952
+        let rules = HostRules::rfc952();
+        assert!(rules.is_valid_host("quake.se"));
+        assert!(rules.is_valid_host("quake1.se"));
+        assert!(rules.is_valid_host("10.10.10.10"));
+        assert!(rules.is_valid_host("::1"));
+        assert!(!rules.is_valid_host("1quake.se"));
+        assert!(!rules.is_valid_host("quake.1se"));
+        assert!(!rules.is_valid_host("my_service"));
+
+        // rfc1123
+        let rules = HostRules::rfc1123();
+        assert!(rules.is_valid_host("1quake.se"));
+        assert!(!rules.is_valid_host("123.quake.se"));
+        assert!(!rules.is_valid_host("my_service"));
+
+        // kubernetes
+        let rules = HostRules::kubernetes();
+        assert!(rules.is_valid_host("my-svc.default.svc.cluster.local"));
+        assert!(!rules.is_valid_host("My-Svc.default"));
+        assert!(!rules.is_valid_host("1svc.default"));
+        assert!(!rules.is_valid_host("my_svc.default"));
+        assert_eq!(
+            rules.validate_host("my-svc.Default"),
+            Err(HostValidationError::InvalidChar {
+                character: 'D',
+                position: 7
+            })
+        );
+
+        // docker
+        let rules = HostRules::docker();
+        assert!(rules.is_valid_host("my_db"));
+        assert!(rules.is_valid_host("MyDb"));
+        assert!(rules.is_valid_host("10.10.10.10"));
+        assert!(rules.is_valid_host("::1"));
+        assert!(!rules.is_valid_host("my db"));
+        assert!(!rules.is_valid_host(&"a".repeat(64)));
+        assert_eq!(
+            rules.validate_host("my_db.internal"),
+            Err(HostValidationError::TooManyLabels {
+                limit: 1,
+                actual: 2
+            })
+        );
+
+        // max length
+        let rules = HostRules {
+            max_length: 8,
+            ..HostRules::default()
+        };
+        assert!(rules.is_valid_host("quake.se"));
+        assert_eq!(
+            rules.validate_host("qw.quake.se"),
+            Err(HostValidationError::TooLong {
+                limit: 8,
+                actual: 11
+            })
+        );
+
+        // max labels
+        let rules = HostRules {
+            max_labels: 2,
+            ..HostRules::default()
+        };
+        assert!(rules.is_valid_host("quake.se"));
+        assert!(rules.is_valid_host("10.10.10.10"));
+        assert_eq!(
+            rules.validate_host("qw.quake.se"),
+            Err(HostValidationError::TooManyLabels {
+                limit: 2,
+                actual: 3
+            })
+        );
+        let rules = HostRules {
+            max_length: 1000,
+            ..HostRules::default()
+        };
+        assert!(rules.is_valid_host(&format!("{}a", "a.".repeat(126))));
+        assert_eq!(
+            rules.validate_host(&format!("{}a", "a.".repeat(127))),
+            Err(HostValidationError::TooManyLabels {
+                limit: 127,
+                actual: 128
+            })
+        );
+
+        // lenient
+        let rules = HostRules::lenient();
+        assert!(rules.is_valid_host("1quake.se"));
+        assert!(rules.is_valid_host("123.quake.se"));
+        assert!(rules.is_valid_host("my_service"));
+        assert!(rules.is_valid_host("0x7f.se"));
+        assert!(!rules.is_valid_host("quake|se"));
+        assert!(!rules.is_valid_host("1000.0.0.0"));
+        assert_eq!(
+            rules.validate_host("127.1"),
+            Err(HostValidationError::NumericTld { position: 4 })
+        );
+        assert_eq!(
+            rules.validate_host("0x7f.1"),
+            Err(HostValidationError::NumericTld { position: 5 })
+        );
+        assert_eq!(
+            rules.validate_host("2130706433"),
+            Err(HostValidationError::NumericTld { position: 0 })
+        );
+    }
+
+    #[test]
+    fn test_host_rules_allow_underscore() {
+        let rules = HostRules {
+            allow_underscore: true,
+            ..HostRules::default()
+        };
+
+        // invalid
+        assert!(!rules.is_valid_host("my service"));
+        assert!(!rules.is_valid_host("-my_service"));
+        assert!(!rules.is_valid_host("_.0"));
+
+        // valid
+        assert!(rules.is_valid_host("my_service"));
+        assert!(rules.is_valid_host("my_service.internal"));
+        assert!(rules.is_valid_host("_quake._udp.quake.se"));
+        assert!(rules.is_valid_host("quake.se"));
+        assert!(!HostRules::default().is_valid_host("my_service"));
+    }
+
+    #[test]
+    fn test_host_rules_allow_numeric_labels() {
+        let rules = HostRules {
+            allow_numeric_labels: true,
+            ..HostRules::default()
+        };
+
+        // invalid
+        assert!(!rules.is_valid_host("1000.0.0.0"));
+        assert!(!rules.is_valid_host("123-.quake.se"));
+
+        // valid
+        assert!(rules.is_valid_host("123.quake.se"));
+        assert!(rules.is_valid_host("quake.123.se"));
+        assert!(rules.is_valid_host("quake.123"));
+        assert!(rules.is_valid_host("10.10.10.10"));
+        assert!(!HostRules::default().is_valid_host("123.quake.se"));
+    }
+
+    #[test]
+    fn test_host_rules_allow_numeric_tld() {
+        let rules = HostRules {
+            allow_numeric_labels: true,
+            allow_numeric_tld: false,
+            ..HostRules::default()
+        };
+
+        // invalid
+        assert_eq!(
+            rules.validate_host("quake.123"),
+            Err(HostValidationError::NumericTld { position: 6 })
+        );
+        assert_eq!(
+            rules.validate_host("123.456"),
+            Err(HostValidationError::NumericTld { position: 4 })
+        );
+        assert_eq!(
+            rules.validate_host("123"),
+            Err(HostValidationError::NumericTld { position: 0 })
+        );
+
+        // valid
+        assert!(rules.is_valid_host("123.quake.se"));
+        assert!(rules.is_valid_host("quake.123.se"));
+        assert!(rules.is_valid_host("10.10.10.10"));
+        assert!(rules.is_valid_host("quake.se1"));
+    }
+
+    #[test]
+    fn test_is_valid_host_with_underscores() {
+        // invalid
+        assert!(!is_valid_host_with_underscores(""));
+        assert!(!is_valid_host_with_underscores("my service"));
+        assert!(!is_valid_host_with_underscores("my_service-"));
+        assert!(!is_valid_host_with_underscores("my_service."));
+
+        // valid
+        assert!(is_valid_host_with_underscores("my_service"));
+        assert!(is_valid_host_with_underscores("my_service.internal"));
+        assert!(is_valid_host_with_underscores("_sip._udp.quake.se"));
+        assert!(is_valid_host_with_underscores("quake.se"));
+        assert!(is_valid_host_with_underscores("10.10.10.10"));
+    }
+
+    #[test]
+    fn test_is_valid_ip() {
+        // invalid
+        assert!(!is_valid_ip(""));
+        assert!(!is_valid_ip("quake.se"));
+        assert!(!is_valid_ip("localhost"));
+        assert!(!is_valid_ip("[::1]"));
+        assert!(!is_valid_ip("fe80::1%eth0"));
+        assert!(!is_valid_ip("1000.0.0.0"));
+
+        // valid
+        assert!(is_valid_ip("10.10.10.10"));
+        assert!(is_valid_ip("::1"));
+        assert!(is_valid_ip("2001:db8::1"));
+    }
+
+    #[test]
+    fn test_is_valid_ipv4() {
+        // invalid
+        assert!(!is_valid_ipv4(""));
+        assert!(!is_valid_ipv4("010.10.10.10"));
+        assert!(!is_valid_ipv4("10.10.10"));
+        assert!(!is_valid_ipv4("1000.0.0.0"));
+        assert!(!is_valid_ipv4("0x7f.0.0.1"));
+        assert!(!is_valid_ipv4("::1"));
+
+        // valid
+        assert!(is_valid_ipv4("0.0.0.0"));
+        assert!(is_valid_ipv4("10.10.10.10"));
+        assert!(is_valid_ipv4("255.255.255.255"));
+    }
+
+    #[test]
+    fn test_is_ipv4_like() {
+        // not ipv4-like
+        assert!(!is_ipv4_like(""));
+        assert!(!is_ipv4_like("."));
+        assert!(!is_ipv4_like("1.2.3.4.5"));
+        assert!(!is_ipv4_like("0x"));
+        assert!(!is_ipv4_like("0xg"));
+        assert!(!is_ipv4_like("quake.se"));
+        assert!(!is_ipv4_like("0xcafe.se"));
+        assert!(!is_ipv4_like("localhost"));
+
+        // ipv4-like
+        assert!(is_ipv4_like("10.10.10.10"));
+        assert!(is_ipv4_like("010.10.10.10"));
+        assert!(is_ipv4_like("1000.0.0.0"));
+        assert!(is_ipv4_like("127.1"));
+        assert!(is_ipv4_like("2130706433"));
+        assert!(is_ipv4_like("0x7f000001"));
+        assert!(is_ipv4_like("0X7F.0.0.1"));
+    }
+
+    #[test]
+    fn test_is_valid_scoped_ipv6() {
+        // invalid
+        assert!(!is_valid_scoped_ipv6("fe80::1%"));
+        assert!(!is_valid_scoped_ipv6("fe80::1%eth 0"));
+        assert!(!is_valid_scoped_ipv6("fe80::1%eth0%1"));
+        assert!(!is_valid_scoped_ipv6("10.10.10.10%eth0"));
+        assert!(!is_valid_scoped_ipv6("%eth0"));
+
+        // valid
+        assert!(is_valid_scoped_ipv6("fe80::1"));
+        assert!(is_valid_scoped_ipv6("fe80::1%eth0"));
+        assert!(is_valid_scoped_ipv6("fe80::1%3"));
+        assert!(is_valid_scoped_ipv6("fe80::1%en0.1"));
+    }
+
+    #[test]
+    fn test_is_valid_ipv6() {
+        // invalid
+        assert!(!is_valid_ipv6(""));
+        assert!(!is_valid_ipv6("[::1]"));
+        assert!(!is_valid_ipv6("10.10.10.10"));
+        assert!(!is_valid_ipv6("quake.se"));
+        assert!(!is_valid_ipv6("2001:db8::g"));
+
+        // valid
+        assert!(is_valid_ipv6("::"));
+        assert!(is_valid_ipv6("::1"));
+        assert!(is_valid_ipv6("2001:db8::1"));
+        assert!(is_valid_ipv6("fe80::1"));
     }
 }